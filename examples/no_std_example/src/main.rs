@@ -3,6 +3,10 @@
 #![no_main]
 #![no_std]
 
+extern crate alloc;
+
+use alloc::string::String;
+use core::alloc::{GlobalAlloc, Layout};
 use core::panic::PanicInfo;
 use nutype::nutype;
 
@@ -11,6 +15,20 @@ fn panic(_panic: &PanicInfo<'_>) -> ! {
     loop {}
 }
 
+// The example is only built, so the allocator never has to allocate.
+struct NoAllocator;
+
+unsafe impl GlobalAlloc for NoAllocator {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        core::ptr::null_mut()
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: NoAllocator = NoAllocator;
+
 // Integer
 #[nutype(
     validate(greater_or_equal = 1, less_or_equal = 6),
@@ -60,7 +78,29 @@ struct GermanTaxClass(i64);
 )]
 struct Width(f64);
 
-// NOTE: strings are not working yet with no_std
+// String
+#[nutype(
+    sanitize(trim, lowercase),
+    validate(not_empty, len_char_max = 20),
+    derive(
+        Debug, Clone, PartialEq, Eq, PartialOrd, Ord, AsRef, Deref, TryFrom, Display
+    )
+)]
+struct Username(String);
+
+#[nutype(
+    validate(len_char_max = 5),
+    derive(Debug, Clone, PartialEq, Eq, FromStr, AsRef, TryFrom)
+)]
+struct Word(String);
+
+#[cfg(feature = "regex")]
+#[nutype(
+    sanitize(trim),
+    validate(regex = "^[a-z][a-z0-9_]*$"),
+    derive(Debug, Clone, PartialEq, Eq, FromStr, AsRef, TryFrom)
+)]
+struct Handle(String);
 
 // Any other type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -73,8 +113,5 @@ pub struct Point {
 ))]
 pub struct Location(Point);
 
-#[nutype(
-    validate(less_or_equal = 100),
-    derive(Serialize, Deserialize)
-)]
+#[nutype(validate(less_or_equal = 100), derive(Serialize, Deserialize))]
 pub struct Percentage(u8);
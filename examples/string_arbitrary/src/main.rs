@@ -9,6 +9,16 @@ fn main() {
     should_respect_not_empty_validation_without_trim();
     should_respect_len_char_max();
     should_respect_both_len_boundaries();
+    should_pick_one_of_allowed_values();
+    should_generate_values_of_formats();
+    should_generate_values_with_valid_checksums();
+    should_respect_len_boundaries_with_remove_whitespace();
+    should_pick_iso_codes_from_tables();
+    should_respect_len_boundaries_with_normalization();
+    should_respect_len_boundaries_with_whitespace_sanitizers();
+    should_respect_truncate_and_replace();
+    should_generate_valid_slugs_and_identifiers();
+    should_generate_values_matching_regex();
 }
 
 fn should_generate_arbitrary_string_without_validation_with_respect_to_sanitizers() {
@@ -79,3 +89,289 @@ fn should_respect_both_len_boundaries() {
         Ok(())
     });
 }
+
+fn should_pick_one_of_allowed_values() {
+    #[nutype(
+        sanitize(trim, uppercase),
+        validate(one_of = ["EUR", "USD", "UAH"]),
+        derive(Arbitrary, Debug)
+    )]
+    struct Currency(String);
+
+    arbtest(|u| {
+        let s = Currency::arbitrary(u)?.into_inner();
+        assert!(["EUR", "USD", "UAH"].contains(&s.as_str()));
+        Ok(())
+    });
+}
+
+fn should_generate_values_of_formats() {
+    use nutype::formats;
+
+    #[nutype(validate(email), derive(Arbitrary, Debug))]
+    struct Email(String);
+
+    #[nutype(
+        sanitize(trim, uppercase),
+        validate(not_empty, url),
+        derive(Arbitrary, Debug)
+    )]
+    struct Url(String);
+
+    #[nutype(validate(uuid), derive(Arbitrary, Debug))]
+    struct Uuid(String);
+
+    #[nutype(validate(ipv4), derive(Arbitrary, Debug))]
+    struct Ipv4(String);
+
+    #[nutype(validate(ipv6), derive(Arbitrary, Debug))]
+    struct Ipv6(String);
+
+    #[nutype(validate(hex), derive(Arbitrary, Debug))]
+    struct Hex(String);
+
+    #[nutype(validate(base64), derive(Arbitrary, Debug))]
+    struct Base64(String);
+
+    #[nutype(validate(semver), derive(Arbitrary, Debug))]
+    struct Version(String);
+
+    #[nutype(validate(iso8601_date), derive(Arbitrary, Debug))]
+    struct Date(String);
+
+    arbtest(|u| {
+        assert!(formats::is_email(&Email::arbitrary(u)?.into_inner()));
+        assert!(formats::is_url(&Url::arbitrary(u)?.into_inner()));
+        assert!(formats::is_uuid(&Uuid::arbitrary(u)?.into_inner()));
+        assert!(formats::is_ipv4(&Ipv4::arbitrary(u)?.into_inner()));
+        assert!(formats::is_ipv6(&Ipv6::arbitrary(u)?.into_inner()));
+        assert!(formats::is_hex(&Hex::arbitrary(u)?.into_inner()));
+        assert!(formats::is_base64(&Base64::arbitrary(u)?.into_inner()));
+        assert!(formats::is_semver(&Version::arbitrary(u)?.into_inner()));
+        assert!(formats::is_iso8601_date(&Date::arbitrary(u)?.into_inner()));
+        Ok(())
+    });
+}
+
+fn should_generate_values_with_valid_checksums() {
+    use nutype::checksums;
+
+    #[nutype(sanitize(remove_whitespace), validate(luhn), derive(Arbitrary, Debug))]
+    struct CardNumber(String);
+
+    #[nutype(validate(iban), derive(Arbitrary, Debug))]
+    struct Iban(String);
+
+    #[nutype(validate(isbn), derive(Arbitrary, Debug))]
+    struct Isbn(String);
+
+    #[nutype(validate(ean), derive(Arbitrary, Debug))]
+    struct Ean(String);
+
+    arbtest(|u| {
+        assert!(checksums::is_luhn(&CardNumber::arbitrary(u)?.into_inner()));
+        assert!(checksums::is_iban(&Iban::arbitrary(u)?.into_inner()));
+        assert!(checksums::is_isbn(&Isbn::arbitrary(u)?.into_inner()));
+        assert!(checksums::is_ean(&Ean::arbitrary(u)?.into_inner()));
+        Ok(())
+    });
+}
+
+fn should_respect_len_boundaries_with_remove_whitespace() {
+    #[nutype(
+        sanitize(trim, remove_whitespace),
+        validate(len_char_min = 3, len_char_max = 5),
+        derive(Arbitrary, Debug)
+    )]
+    struct Code(String);
+
+    arbtest(|u| {
+        let s = Code::arbitrary(u)?.into_inner();
+        assert!(!s.contains(char::is_whitespace));
+        let len = s.chars().count();
+        assert!((3..=5).contains(&len), "len is {len}");
+        Ok(())
+    });
+}
+
+fn should_pick_iso_codes_from_tables() {
+    use nutype::iso_codes;
+
+    #[nutype(
+        sanitize(trim, uppercase),
+        validate(iso3166_alpha2),
+        derive(Arbitrary, Debug)
+    )]
+    struct CountryCode(String);
+
+    #[nutype(validate(iso3166_alpha3), derive(Arbitrary, Debug))]
+    struct CountryCode3(String);
+
+    #[nutype(validate(iso4217), derive(Arbitrary, Debug))]
+    struct CurrencyCode(String);
+
+    #[nutype(validate(bcp47), derive(Arbitrary, Debug))]
+    struct LanguageTag(String);
+
+    arbtest(|u| {
+        let country = CountryCode::arbitrary(u)?.into_inner();
+        assert!(iso_codes::ISO3166_ALPHA2.contains(&country.as_str()));
+        let country = CountryCode3::arbitrary(u)?.into_inner();
+        assert!(iso_codes::ISO3166_ALPHA3.contains(&country.as_str()));
+        let currency = CurrencyCode::arbitrary(u)?.into_inner();
+        assert!(iso_codes::ISO4217.contains(&currency.as_str()));
+        assert!(iso_codes::is_bcp47(
+            &LanguageTag::arbitrary(u)?.into_inner()
+        ));
+        Ok(())
+    });
+}
+
+fn should_respect_len_boundaries_with_normalization() {
+    #[nutype(
+        sanitize(nfkd, casefold),
+        validate(len_char_min = 2, len_char_max = 8),
+        derive(Arbitrary, Debug)
+    )]
+    struct Username(String);
+
+    arbtest(|u| {
+        let s = Username::arbitrary(u)?.into_inner();
+        let len = s.chars().count();
+        assert!((2..=8).contains(&len), "len is {len}");
+        Ok(())
+    });
+}
+
+fn should_respect_len_boundaries_with_whitespace_sanitizers() {
+    #[nutype(
+        sanitize(trim_start, trim_end, collapse_whitespace, strip_control_chars),
+        validate(len_char_min = 1, len_char_max = 6),
+        derive(Arbitrary, Debug)
+    )]
+    struct Title(String);
+
+    #[nutype(sanitize(trim_end), validate(not_empty), derive(Arbitrary, Debug))]
+    struct Comment(String);
+
+    arbtest(|u| {
+        let s = Title::arbitrary(u)?.into_inner();
+        assert_eq!(s.trim(), s);
+        assert!(!s.contains("  "));
+        assert!(!s.contains(|c: char| c.is_control() || c == '\u{200b}'));
+        let len = s.chars().count();
+        assert!((1..=6).contains(&len), "len is {len}");
+
+        let s = Comment::arbitrary(u)?.into_inner();
+        assert_eq!(s.trim_end(), s);
+        assert!(!s.is_empty());
+        Ok(())
+    });
+}
+
+fn should_respect_truncate_and_replace() {
+    #[nutype(
+        sanitize(trim, truncate = 4, replace(from = "\t", to = "    ")),
+        validate(len_char_min = 2, len_char_max = 4),
+        derive(Arbitrary, Debug)
+    )]
+    struct Code(String);
+
+    arbtest(|u| {
+        let s = Code::arbitrary(u)?.into_inner();
+        assert!(!s.contains('\t'));
+        let len = s.chars().count();
+        assert!((2..=4).contains(&len), "len is {len}");
+        Ok(())
+    });
+}
+
+fn should_generate_valid_slugs_and_identifiers() {
+    #[nutype(
+        sanitize(slugify),
+        validate(slug, len_char_max = 12),
+        derive(Arbitrary, Debug)
+    )]
+    struct Slug(String);
+
+    #[nutype(
+        validate(slug(separator = '_'), identifier, len_char_min = 3),
+        derive(Arbitrary, Debug)
+    )]
+    struct Column(String);
+
+    #[nutype(validate(identifier), derive(Arbitrary, Debug))]
+    struct FieldName(String);
+
+    #[nutype(
+        sanitize(slugify(separator = '.')),
+        validate(len_char_min = 1, len_char_max = 5),
+        derive(Arbitrary, Debug)
+    )]
+    struct Path(String);
+
+    arbtest(|u| {
+        let s = Slug::arbitrary(u)?.into_inner();
+        assert!(!s.is_empty() && s.chars().count() <= 12, "{s:?}");
+        assert!(!s.starts_with('-') && !s.ends_with('-') && !s.contains("--"));
+
+        let s = Column::arbitrary(u)?.into_inner();
+        assert!(s.chars().count() >= 3, "{s:?}");
+        assert!(!s.starts_with(|c: char| c == '_' || c.is_ascii_digit()));
+
+        let s = FieldName::arbitrary(u)?.into_inner();
+        assert!(!s.starts_with(|c: char| c.is_ascii_digit()));
+        assert!(s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+
+        let s = Path::arbitrary(u)?.into_inner();
+        assert!(!s.starts_with('.') && !s.ends_with('.') && !s.contains(".."));
+        Ok(())
+    });
+}
+
+fn should_generate_values_matching_regex() {
+    #[nutype(
+        validate(regex = r"^\+[1-9][0-9]{0,2}-[0-9]{3}-[0-9]{4}$"),
+        derive(Arbitrary, Debug)
+    )]
+    struct PhoneNumber(String);
+
+    #[nutype(
+        validate(regex = "^(ORD|INV)-[A-Z]{2}[0-9]+(-[a-z]+)?$", len_char_max = 12),
+        derive(Arbitrary, Debug)
+    )]
+    struct DocumentId(String);
+
+    #[nutype(
+        validate(not_empty, regex = r"^[a-zA-Zа-я]*\d*$", len_char_min = 3),
+        derive(Arbitrary, Debug)
+    )]
+    struct Login(String);
+
+    #[nutype(
+        sanitize(trim, lowercase),
+        validate(regex = r"^\s*[a-zA-Z]+@example\.(com|org)$"),
+        derive(Arbitrary, Debug)
+    )]
+    struct Email(String);
+
+    arbtest(|u| {
+        let s = PhoneNumber::arbitrary(u)?.into_inner();
+        assert!(s.starts_with('+') && s.len() <= 14, "{s:?}");
+
+        let s = DocumentId::arbitrary(u)?.into_inner();
+        assert!(s.starts_with("ORD-") || s.starts_with("INV-"), "{s:?}");
+        assert!(s.chars().count() <= 12, "{s:?}");
+
+        let s = Login::arbitrary(u)?.into_inner();
+        assert!(s.chars().count() >= 3, "{s:?}");
+
+        let s = Email::arbitrary(u)?.into_inner();
+        assert_eq!(s.trim().to_lowercase(), s);
+        assert!(
+            s.ends_with("@example.com") || s.ends_with("@example.org"),
+            "{s:?}"
+        );
+        Ok(())
+    });
+}
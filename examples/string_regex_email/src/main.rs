@@ -1,7 +1,6 @@
 use std::sync::LazyLock;
 
-use nutype::nutype;
-use regex::Regex;
+use nutype::{nutype, regex::Regex};
 
 // Note: this regex is very simplified.
 // In reality you'd like to use a more sophisticated regex for email validation.
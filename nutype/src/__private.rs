@@ -0,0 +1,608 @@
+//! Helpers used by the code generated with `#[nutype]` macro.
+//! They are not part of the public API and can change at any time.
+
+/// Custom sanitizer given with `sanitize(with = ...)`.
+/// It allows the sanitizer to be either a function that takes and returns the value
+/// (`fn(String) -> String`) or a function that modifies the value in place (`fn(&mut String)`).
+/// `Marker` is inferred by the compiler, since a function implements only one of the variants.
+pub trait SanitizeWith<T, Marker> {
+    fn sanitize_with(self, value: &mut T);
+}
+
+#[doc(hidden)]
+pub enum ByValue {}
+
+#[doc(hidden)]
+pub enum InPlace {}
+
+impl<T: Default, F: FnOnce(T) -> T> SanitizeWith<T, ByValue> for F {
+    #[inline]
+    fn sanitize_with(self, value: &mut T) {
+        *value = self(core::mem::take(value));
+    }
+}
+
+impl<T, F: FnOnce(&mut T)> SanitizeWith<T, InPlace> for F {
+    #[inline]
+    fn sanitize_with(self, value: &mut T) {
+        self(value);
+    }
+}
+
+/// Counts chars and UTF-16 code units of a string in a single pass.
+///
+/// Counting stops as soon as both counts exceed the given limits, because the validation
+/// result is already known then (e.g. `len_char_max` is violated). Pass `usize::MAX` to get
+/// the exact count and `0` if the count is not needed at all.
+pub fn count_chars_and_utf16(val: &str, char_limit: usize, utf16_limit: usize) -> (usize, usize) {
+    // Chunks are small enough to stop early on long inputs and large enough
+    // to make the ASCII check cheap.
+    const CHUNK_SIZE: usize = 64;
+
+    let mut chars_count = 0;
+    let mut utf16_count = 0;
+    for chunk in val.as_bytes().chunks(CHUNK_SIZE) {
+        if chunk.is_ascii() {
+            chars_count += chunk.len();
+            utf16_count += chunk.len();
+        } else {
+            for &byte in chunk {
+                // Every char starts with a non-continuation byte, and chars encoded with
+                // 4 bytes (leading byte 0xF0..) take 2 code units in UTF-16.
+                if byte & 0xC0 != 0x80 {
+                    chars_count += 1;
+                    utf16_count += if byte >= 0xF0 { 2 } else { 1 };
+                }
+            }
+        }
+        if chars_count > char_limit && utf16_count > utf16_limit {
+            break;
+        }
+    }
+    (chars_count, utf16_count)
+}
+
+/// Built-in string sanitizers, that transform the value char by char and are fused
+/// by the macro into a single pass, e.g. `sanitize(trim, collapse_whitespace, lowercase)`.
+pub mod sanitize {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+
+    /// Output of [`rewrite_chars`]. The chars are written over the already read part
+    /// of the input, so the value is moved to a new buffer only if the output outgrows the input
+    /// (e.g. `İ` is lowercased into two chars).
+    pub struct CharWriter {
+        bytes: Vec<u8>,
+        read: usize,
+        write: usize,
+        spill: Option<String>,
+    }
+
+    impl CharWriter {
+        #[inline]
+        pub fn push(&mut self, c: char) {
+            if let Some(spill) = &mut self.spill {
+                spill.push(c);
+                return;
+            }
+            let len = c.len_utf8();
+            if self.write + len <= self.read {
+                c.encode_utf8(&mut self.bytes[self.write..self.write + len]);
+                self.write += len;
+            } else {
+                let mut spill = String::with_capacity(self.bytes.len() + len);
+                // SAFETY: Only whole chars are written before `write`.
+                spill
+                    .push_str(unsafe { core::str::from_utf8_unchecked(&self.bytes[..self.write]) });
+                spill.push(c);
+                self.spill = Some(spill);
+            }
+        }
+    }
+
+    /// Passes every char of the value (trimmed, if requested) to `f`, which pushes
+    /// the sanitized chars to the output. The result replaces the value.
+    /// The value is rewritten within its own buffer, so it's not reallocated, unless it grows.
+    pub fn rewrite_chars(
+        value: &mut String,
+        trim_start: bool,
+        trim_end: bool,
+        mut f: impl FnMut(char, &mut CharWriter),
+    ) {
+        let mut trimmed = value.as_str();
+        if trim_end {
+            trimmed = trimmed.trim_end();
+        }
+        let end = trimmed.len();
+        if trim_start {
+            trimmed = trimmed.trim_start();
+        }
+        let start = end - trimmed.len();
+
+        let mut output = CharWriter {
+            bytes: core::mem::take(value).into_bytes(),
+            read: start,
+            write: 0,
+            spill: None,
+        };
+        while output.read < end {
+            // SAFETY: The bytes after `read` are not overwritten, since `write` never exceeds
+            // `read`, so they are still valid UTF-8, and `read` is at a char boundary.
+            let rest = unsafe { core::str::from_utf8_unchecked(&output.bytes[output.read..end]) };
+            let c = rest.chars().next().expect("rest is not empty");
+            output.read += c.len_utf8();
+            f(c, &mut output);
+        }
+        *value = match output.spill {
+            Some(spill) => spill,
+            None => {
+                let mut bytes = output.bytes;
+                bytes.truncate(output.write);
+                // SAFETY: Only whole chars are written before `write`.
+                unsafe { String::from_utf8_unchecked(bytes) }
+            }
+        };
+    }
+}
+
+/// Characters removed by `strip_control_chars` sanitizer: control characters (e.g. `\0`, `\t`)
+/// and invisible zero-width characters, including the byte order mark.
+pub fn is_control_or_zero_width(c: char) -> bool {
+    c.is_control() || matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}')
+}
+
+/// Checks `slug` validator: lowercase ASCII letters and digits, words are joined with
+/// a single separator, e.g. `hello-world`.
+pub fn is_slug(val: &str, separator: char) -> bool {
+    val.split(separator).all(|word| {
+        !word.is_empty()
+            && word
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+    })
+}
+
+/// Checks `identifier` validator: ASCII letters, digits and underscores, not starting
+/// with a digit, like identifiers in Rust or C.
+pub fn is_identifier(val: &str) -> bool {
+    let mut bytes = val.bytes();
+    matches!(bytes.next(), Some(b) if b.is_ascii_alphabetic() || b == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// Counts extended grapheme clusters (user-perceived characters) of a string.
+///
+/// Counting stops as soon as the count exceeds the given limit, the same way as in
+/// [`count_chars_and_utf16`]. Pass `usize::MAX` to get the exact count.
+#[cfg(feature = "unicode")]
+pub fn count_graphemes(val: &str, limit: usize) -> usize {
+    unicode_segmentation::UnicodeSegmentation::graphemes(val, true)
+        .take(limit.saturating_add(1))
+        .count()
+}
+
+/// Unicode normalization forms and case folding, used by `nfc`, `nfd`, `nfkc`, `nfkd`,
+/// `casefold` and `slugify` sanitizers, and truncation by grapheme clusters.
+///
+/// The value is reallocated only if it is not normalized yet.
+#[cfg(feature = "unicode")]
+pub mod unicode {
+    extern crate alloc;
+
+    use alloc::string::String;
+    use unicode_normalization::{IsNormalized, UnicodeNormalization};
+
+    pub fn nfc(value: &mut String) {
+        if unicode_normalization::is_nfc_quick(value.chars()) != IsNormalized::Yes {
+            *value = value.nfc().collect();
+        }
+    }
+
+    pub fn nfd(value: &mut String) {
+        if unicode_normalization::is_nfd_quick(value.chars()) != IsNormalized::Yes {
+            *value = value.nfd().collect();
+        }
+    }
+
+    pub fn nfkc(value: &mut String) {
+        if unicode_normalization::is_nfkc_quick(value.chars()) != IsNormalized::Yes {
+            *value = value.nfkc().collect();
+        }
+    }
+
+    pub fn nfkd(value: &mut String) {
+        if unicode_normalization::is_nfkd_quick(value.chars()) != IsNormalized::Yes {
+            *value = value.nfkd().collect();
+        }
+    }
+
+    /// Full case folding as defined by Unicode (e.g. `ß` becomes `ss`), which is meant
+    /// for caseless matching rather than for displaying.
+    pub fn casefold(value: &mut String) {
+        if value.is_ascii() {
+            value.make_ascii_lowercase();
+        } else {
+            *value = caseless::default_case_fold_str(value);
+        }
+    }
+
+    /// Transliterates the value into ASCII (e.g. `ü` becomes `u`), lowercases it and joins
+    /// the words with the separator. Every run of other chars is a boundary between words.
+    pub fn slugify(value: &mut String, separator: char) {
+        if super::is_slug(value, separator) {
+            return;
+        }
+        let mut slug = String::with_capacity(value.len());
+        let mut is_word_boundary = false;
+        for c in value.chars() {
+            // Chars without transliteration separate words too.
+            let ascii = deunicode::deunicode_char(c).unwrap_or(" ");
+            for b in ascii.bytes() {
+                if b.is_ascii_alphanumeric() {
+                    if is_word_boundary && !slug.is_empty() {
+                        slug.push(separator);
+                    }
+                    is_word_boundary = false;
+                    slug.push(char::from(b.to_ascii_lowercase()));
+                } else {
+                    is_word_boundary = true;
+                }
+            }
+        }
+        *value = slug;
+    }
+
+    /// Keeps the first `len` grapheme clusters, so e.g. a flag is never cut in half.
+    pub fn truncate_graphemes(value: &mut String, len: usize) {
+        let maybe_end =
+            unicode_segmentation::UnicodeSegmentation::grapheme_indices(value.as_str(), true)
+                .nth(len)
+                .map(|(end, _)| end);
+        if let Some(end) = maybe_end {
+            value.truncate(end);
+        }
+    }
+}
+
+/// Regexes given as string literals to `regex` and `not_regex` validators.
+///
+/// The engine is picked by the features: `regex` takes precedence over `regex_lite`.
+/// The rules with `bytes` flag use `regex::bytes` engine instead, that matches the bytes of the value.
+/// The macro validates the patterns with the same engine.
+/// A regex is compiled on its first use, with `once_cell::race::OnceBox` in `no_std` environment.
+#[cfg(any(feature = "regex", feature = "regex_lite"))]
+pub mod regex {
+    #[cfg(not(feature = "std"))]
+    extern crate alloc;
+
+    #[cfg(feature = "regex")]
+    use ::regex::Regex;
+    #[cfg(not(feature = "regex"))]
+    use ::regex_lite::Regex;
+
+    #[cfg(feature = "std")]
+    type Cell<R> = std::sync::OnceLock<R>;
+    #[cfg(not(feature = "std"))]
+    type Cell<R> = once_cell::race::OnceBox<R>;
+
+    /// Regex of `regex` and `not_regex` rules.
+    pub type LazyRegex = Lazy<Regex>;
+
+    /// Regex of `regex(pattern = "...", bytes)` rules.
+    #[cfg(feature = "regex")]
+    pub type LazyBytesRegex = Lazy<::regex::bytes::Regex>;
+
+    /// Regex engine, that matches the values of string newtypes.
+    pub trait Engine: Sized {
+        fn build(pattern: &str) -> Self;
+
+        fn is_match(&self, haystack: &str) -> bool;
+    }
+
+    // The patterns are validated at compile time, so they can fail only on the size limits.
+
+    impl Engine for Regex {
+        fn build(pattern: &str) -> Self {
+            Regex::new(pattern).expect("Nutype failed to build a regex")
+        }
+
+        fn is_match(&self, haystack: &str) -> bool {
+            Regex::is_match(self, haystack)
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    impl Engine for ::regex::bytes::Regex {
+        fn build(pattern: &str) -> Self {
+            ::regex::bytes::Regex::new(pattern).expect("Nutype failed to build a regex")
+        }
+
+        fn is_match(&self, haystack: &str) -> bool {
+            ::regex::bytes::Regex::is_match(self, haystack.as_bytes())
+        }
+    }
+
+    pub struct Lazy<R> {
+        pattern: &'static str,
+        cell: Cell<R>,
+    }
+
+    impl<R: Engine> Lazy<R> {
+        pub const fn new(pattern: &'static str) -> Self {
+            Self {
+                pattern,
+                cell: Cell::new(),
+            }
+        }
+
+        pub fn is_match(&self, haystack: &str) -> bool {
+            self.get().is_match(haystack)
+        }
+
+        fn get(&self) -> &R {
+            #[cfg(feature = "std")]
+            let regex = self.cell.get_or_init(|| R::build(self.pattern));
+            #[cfg(not(feature = "std"))]
+            let regex = self
+                .cell
+                .get_or_init(|| alloc::boxed::Box::new(R::build(self.pattern)));
+            regex
+        }
+    }
+
+    impl LazyRegex {
+        /// Byte range of the named capture group within the first match.
+        /// Used by the accessors of `regex(pattern = "...", captures)` rules.
+        pub fn capture_range(
+            &self,
+            haystack: &str,
+            group: &str,
+        ) -> Option<core::ops::Range<usize>> {
+            let capture = self.get().captures(haystack)?.name(group)?;
+            Some(capture.start()..capture.end())
+        }
+    }
+
+    #[cfg(feature = "regex_dfa")]
+    pub use regex_automata::util::wire::AlignAs;
+
+    /// Regex compiled by the macro to a dense DFA with `regex(pattern = "...", dfa)`.
+    /// The DFA is serialized in the byte order of the target and embedded into the binary,
+    /// so it is deserialized in constant time, without a lazy static.
+    #[cfg(feature = "regex_dfa")]
+    pub struct EmbeddedDfa {
+        /// Must be produced by the macro, since the tables are not validated on deserialization.
+        pub bytes: &'static AlignAs<[u8], u32>,
+    }
+
+    #[cfg(feature = "regex_dfa")]
+    impl EmbeddedDfa {
+        pub fn is_match(&self, haystack: &str) -> bool {
+            use regex_automata::{
+                Input,
+                dfa::{Automaton, dense::DFA},
+            };
+
+            // SAFETY: The bytes are produced by `DFA::to_bytes_little_endian()` or
+            // `DFA::to_bytes_big_endian()` within the macro. `from_bytes_unchecked` still checks
+            // the alignment, the byte order and the version of the format, but skips the validation
+            // of the transition table, that takes linear time.
+            let (dfa, _) = unsafe { DFA::from_bytes_unchecked(&self.bytes.bytes) }
+                .expect("Nutype failed to deserialize a DFA");
+            let input = Input::new(haystack).earliest(true);
+            dfa.try_search_fwd(&input)
+                .expect("DFA built without quit bytes never fails")
+                .is_some()
+        }
+    }
+}
+
+/// Generation of arbitrary strings, that match a regex literal of `regex` validator.
+///
+/// The macro converts the syntax tree of the regex into `Node`s, so neither the regex engine
+/// nor `arbitrary` crate are needed here: the random choices are made by the generated code.
+#[cfg(feature = "arbitrary")]
+pub mod regex_arbitrary {
+    extern crate alloc;
+
+    use alloc::string::String;
+
+    /// Additional repetitions of `*`, `+` and `{n,}`, if the length is not limited otherwise.
+    const MAX_EXTRA_REPETITIONS: usize = 8;
+
+    pub enum Node {
+        Literal(&'static str),
+        /// Inclusive ranges of chars.
+        Class(&'static [(char, char)]),
+        Concat(&'static [Node]),
+        Alternation(&'static [Node]),
+        Repetition {
+            min: usize,
+            max: Option<usize>,
+            sub: &'static Node,
+        },
+    }
+
+    impl Node {
+        /// Minimal number of chars of a match.
+        fn min_len(&self) -> usize {
+            match self {
+                Node::Literal(literal) => literal.chars().count(),
+                Node::Class(_) => 1,
+                Node::Concat(nodes) => nodes.iter().map(Node::min_len).sum(),
+                Node::Alternation(nodes) => nodes.iter().map(Node::min_len).min().unwrap_or(0),
+                Node::Repetition { min, sub, .. } => min.saturating_mul(sub.min_len()),
+            }
+        }
+
+        /// Maximal number of chars of a match, `None` if it's unbounded.
+        fn max_len(&self) -> Option<usize> {
+            match self {
+                Node::Literal(literal) => Some(literal.chars().count()),
+                Node::Class(_) => Some(1),
+                Node::Concat(nodes) => nodes.iter().map(Node::max_len).sum(),
+                Node::Alternation(nodes) => nodes
+                    .iter()
+                    .map(Node::max_len)
+                    .try_fold(0, |acc, len| Some(acc.max(len?))),
+                Node::Repetition { max, sub, .. } => match (max, sub.max_len()) {
+                    (_, Some(0)) => Some(0),
+                    (Some(max), Some(sub_max)) => Some(max.saturating_mul(sub_max)),
+                    _ => None,
+                },
+            }
+        }
+    }
+
+    /// Generates a string, that matches the node and has from `min_len` to `max_len` chars,
+    /// if the regex allows it. `choose(min, max)` must return a number within `min..=max`.
+    pub fn generate<E>(
+        node: &Node,
+        min_len: usize,
+        max_len: usize,
+        choose: &mut impl FnMut(usize, usize) -> Result<usize, E>,
+    ) -> Result<String, E> {
+        let mut output = String::new();
+        generate_node(node, min_len, max_len, &mut output, choose)?;
+        Ok(output)
+    }
+
+    fn generate_node<E>(
+        node: &Node,
+        min_len: usize,
+        max_len: usize,
+        output: &mut String,
+        choose: &mut impl FnMut(usize, usize) -> Result<usize, E>,
+    ) -> Result<(), E> {
+        match node {
+            Node::Literal(literal) => output.push_str(literal),
+            Node::Class(ranges) => output.push(choose_char(ranges, choose)?),
+            Node::Concat(nodes) => {
+                generate_sequence(nodes.iter(), min_len, max_len, output, choose)?;
+            }
+            Node::Alternation(nodes) => {
+                // Prefer the branches, that fit the length.
+                let fits = |node: &&Node| {
+                    node.min_len() <= max_len && node.max_len().is_none_or(|len| len >= min_len)
+                };
+                let fitting_count = nodes.iter().filter(fits).count();
+                let branch = if fitting_count == 0 {
+                    &nodes[choose(0, nodes.len() - 1)?]
+                } else {
+                    let index = choose(0, fitting_count - 1)?;
+                    nodes
+                        .iter()
+                        .filter(fits)
+                        .nth(index)
+                        .expect("index is within the count")
+                };
+                generate_node(branch, min_len, max_len, output, choose)?;
+            }
+            Node::Repetition { min, max, sub } => {
+                let sub_min_len = sub.min_len();
+                // Fewest repetitions, that can reach `min_len`.
+                let min_count = match sub.max_len() {
+                    Some(0) => *min,
+                    Some(sub_max_len) => (*min).max(min_len.div_ceil(sub_max_len)),
+                    None => *min,
+                };
+                let mut max_count = max.unwrap_or(min_count.saturating_add(MAX_EXTRA_REPETITIONS));
+                if let Some(count) = max_len.checked_div(sub_min_len) {
+                    max_count = max_count.min(count);
+                }
+                let min_count = min_count.min(max.unwrap_or(usize::MAX));
+                let count = choose(min_count, max_count.max(min_count))?;
+                let subs = core::iter::repeat_n(*sub, count);
+                generate_sequence(subs, min_len, max_len, output, choose)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates the nodes one after another, so that the rest of the nodes can still fit
+    /// the remaining length.
+    fn generate_sequence<'a, E>(
+        nodes: impl Iterator<Item = &'a Node> + Clone,
+        min_len: usize,
+        max_len: usize,
+        output: &mut String,
+        choose: &mut impl FnMut(usize, usize) -> Result<usize, E>,
+    ) -> Result<(), E> {
+        let mut min_len = min_len;
+        let mut max_len = max_len;
+        let mut rest = nodes;
+        while let Some(node) = rest.next() {
+            let rest_min_len: usize = rest.clone().map(Node::min_len).sum();
+            let rest_max_len: Option<usize> = rest.clone().map(Node::max_len).sum();
+            let node_min_len = match rest_max_len {
+                Some(rest_max_len) => min_len.saturating_sub(rest_max_len),
+                None => 0,
+            };
+            let node_max_len = max_len.saturating_sub(rest_min_len);
+            let start = output.len();
+            generate_node(node, node_min_len, node_max_len, output, choose)?;
+            let len = output[start..].chars().count();
+            min_len = min_len.saturating_sub(len);
+            max_len = max_len.saturating_sub(len);
+        }
+        Ok(())
+    }
+
+    fn choose_char<E>(
+        ranges: &[(char, char)],
+        choose: &mut impl FnMut(usize, usize) -> Result<usize, E>,
+    ) -> Result<char, E> {
+        // Surrogates are not chars, so they are skipped to keep the choice uniform.
+        // A range cannot start or end within them, so it either spans all of them or none.
+        let spans_surrogates = |&(start, end): &(char, char)| {
+            (start as u32) < *SURROGATES.start() && (end as u32) > *SURROGATES.end()
+        };
+        let size = |range: &(char, char)| {
+            let size = range.1 as u32 - range.0 as u32 + 1;
+            if spans_surrogates(range) {
+                (size - SURROGATES_LEN) as usize
+            } else {
+                size as usize
+            }
+        };
+        let total: usize = ranges.iter().map(size).sum();
+        let mut index = choose(0, total - 1)?;
+        for range in ranges {
+            if index < size(range) {
+                let mut code = range.0 as u32 + index as u32;
+                if spans_surrogates(range) && code >= *SURROGATES.start() {
+                    code += SURROGATES_LEN;
+                }
+                return Ok(char::from_u32(code).expect("surrogates are skipped"));
+            }
+            index -= size(range);
+        }
+        unreachable!("index is within the total size of the ranges")
+    }
+
+    /// Code points reserved for UTF-16 surrogates, that are not valid chars.
+    const SURROGATES: core::ops::RangeInclusive<u32> = 0xD800..=0xDFFF;
+    const SURROGATES_LEN: u32 = 0x800;
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_choose_char_skips_surrogates() {
+            let ranges = [('a', 'b'), ('\u{D7FE}', '\u{E001}')];
+            let chars = [0, 1, 2, 3, 4, 5].map(|index| {
+                choose_char(&ranges, &mut |min, max| {
+                    assert_eq!((min, max), (0, 5));
+                    Ok::<_, ()>(index)
+                })
+                .unwrap()
+            });
+            assert_eq!(
+                chars,
+                ['a', 'b', '\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']
+            );
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Checksum algorithms used by the built-in checksum validators (`luhn`, `iban`, `isbn` and `ean`).
+//!
+//! The validators call these functions, so they can also be used directly
+//! to check a value without constructing a newtype:
+//!
+//! ```
+//! assert!(nutype::checksums::is_iban("DE89370400440532013000"));
+//! assert!(!nutype::checksums::is_luhn("4111111111111112"));
+//! ```
+//!
+//! All the checks are pure functions, that work without allocation and in `no_std` environment.
+
+/// Checks that the value is a non-empty sequence of ASCII digits with a valid
+/// [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) check digit at the end,
+/// as used by payment card numbers or IMEI. Separators (spaces, hyphens) are not accepted.
+///
+/// ```
+/// use nutype::checksums::is_luhn;
+///
+/// assert!(is_luhn("4111111111111111"));
+/// assert!(!is_luhn("4111111111111112"));
+/// assert!(!is_luhn("4111 1111 1111 1111"));
+/// ```
+pub fn is_luhn(value: &str) -> bool {
+    !value.is_empty()
+        && value.bytes().all(|c| c.is_ascii_digit())
+        && value
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(index, c)| luhn_digit(u32::from(c - b'0'), index % 2 == 1))
+            .sum::<u32>()
+            .is_multiple_of(10)
+}
+
+/// Checks that the decimal representation of the number has a valid
+/// [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) check digit (the last digit).
+/// It's used by the `luhn` validator of integer types.
+///
+/// ```
+/// use nutype::checksums::is_luhn_number;
+///
+/// assert!(is_luhn_number(79927398713));
+/// assert!(!is_luhn_number(79927398710));
+/// ```
+pub const fn is_luhn_number(value: u128) -> bool {
+    let mut rest = value;
+    let mut sum = 0;
+    let mut is_doubled = false;
+    loop {
+        sum += luhn_digit((rest % 10) as u32, is_doubled);
+        is_doubled = !is_doubled;
+        rest /= 10;
+        if rest == 0 {
+            return sum.is_multiple_of(10);
+        }
+    }
+}
+
+/// Checks that the value is an International Bank Account Number in the electronic format
+/// ([ISO 13616](https://en.wikipedia.org/wiki/International_Bank_Account_Number)):
+/// 15-34 characters, a country code of two uppercase ASCII letters, two check digits,
+/// and uppercase ASCII letters or digits of the account number.
+/// The check digits must pass the MOD 97-10 check.
+/// Country-specific lengths are not checked.
+///
+/// Spaces and lowercase letters are not accepted, consider `remove_whitespace` and `uppercase` sanitizers.
+///
+/// ```
+/// use nutype::checksums::is_iban;
+///
+/// assert!(is_iban("GB82WEST12345698765432"));
+/// assert!(!is_iban("GB83WEST12345698765432"));
+/// assert!(!is_iban("GB82 WEST 1234 5698 7654 32"));
+/// ```
+pub fn is_iban(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let is_valid_shape = (15..=34).contains(&bytes.len())
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..4].iter().all(u8::is_ascii_digit)
+        && bytes[4..]
+            .iter()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase());
+    if !is_valid_shape {
+        return false;
+    }
+
+    // The first four characters are moved to the end, the letters are replaced with numbers
+    // (A = 10, ..., Z = 35), and the resulting number must give the remainder 1 modulo 97.
+    let remainder = bytes[4..].iter().chain(&bytes[..4]).fold(0u32, |acc, &c| {
+        if c.is_ascii_digit() {
+            (acc * 10 + u32::from(c - b'0')) % 97
+        } else {
+            (acc * 100 + u32::from(c - b'A' + 10)) % 97
+        }
+    });
+    remainder == 1
+}
+
+/// Checks that the value is an [ISBN](https://en.wikipedia.org/wiki/ISBN) without separators:
+///
+/// * ISBN-10: 9 digits followed by a check digit or `X`, the MOD 11 check must pass.
+/// * ISBN-13: 13 digits starting with `978` or `979`, the check digit is computed the same way as for EAN-13.
+///
+/// ```
+/// use nutype::checksums::is_isbn;
+///
+/// assert!(is_isbn("080442957X"));
+/// assert!(is_isbn("9780306406157"));
+/// assert!(!is_isbn("9780306406158"));
+/// assert!(!is_isbn("978-0-306-40615-7"));
+/// ```
+pub fn is_isbn(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    match bytes {
+        [digits @ .., check] if bytes.len() == 10 => {
+            let is_valid_shape =
+                digits.iter().all(u8::is_ascii_digit) && (check.is_ascii_digit() || *check == b'X');
+            is_valid_shape
+                && bytes
+                    .iter()
+                    .zip((1..=10).rev())
+                    .map(|(&c, weight)| {
+                        let digit = if c == b'X' { 10 } else { u32::from(c - b'0') };
+                        weight * digit
+                    })
+                    .sum::<u32>()
+                    .is_multiple_of(11)
+        }
+        [b'9', b'7', b'8' | b'9', ..] if bytes.len() == 13 => is_ean(value),
+        _ => false,
+    }
+}
+
+/// Checks that the value is an [EAN](https://en.wikipedia.org/wiki/International_Article_Number)
+/// (EAN-8 or EAN-13): 8 or 13 digits, where the last one is a valid check digit.
+///
+/// ```
+/// use nutype::checksums::is_ean;
+///
+/// assert!(is_ean("4006381333931"));
+/// assert!(is_ean("73513537"));
+/// assert!(!is_ean("4006381333932"));
+/// ```
+pub fn is_ean(value: &str) -> bool {
+    matches!(value.len(), 8 | 13)
+        && value.bytes().all(|c| c.is_ascii_digit())
+        && value
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(index, c)| {
+                // Starting from the check digit, the weights alternate: 1, 3, 1, 3, ...
+                let weight = if index % 2 == 1 { 3 } else { 1 };
+                weight * u32::from(c - b'0')
+            })
+            .sum::<u32>()
+            .is_multiple_of(10)
+}
+
+/// Value of the digit in the Luhn sum: every second digit from the right is doubled,
+/// and the digits of the product are summed up.
+const fn luhn_digit(digit: u32, is_doubled: bool) -> u32 {
+    match (is_doubled, digit * 2) {
+        (false, _) => digit,
+        (true, doubled) if doubled > 9 => doubled - 9,
+        (true, doubled) => doubled,
+    }
+}
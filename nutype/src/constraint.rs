@@ -0,0 +1,66 @@
+/// Sanitizer or validator declared within `#[nutype]` macro.
+///
+/// Every generated type exposes its sanitizers and validators (in the order of declaration)
+/// through `CONSTRAINTS` associated constant, so they can be rendered by frontends,
+/// docs generators, etc. without duplicating the rules:
+///
+/// ```
+/// use nutype::{nutype, Constraint, ConstraintValue};
+///
+/// #[nutype(sanitize(trim), validate(not_empty, len_char_max = 20))]
+/// pub struct Username(String);
+///
+/// assert_eq!(
+///     Username::CONSTRAINTS,
+///     &[
+///         Constraint::Sanitizer { kind: "trim" },
+///         Constraint::Validator { kind: "not_empty", value: None },
+///         Constraint::Validator {
+///             kind: "len_char_max",
+///             value: Some(ConstraintValue::Length(20)),
+///         },
+///     ],
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constraint {
+    /// Sanitizer, e.g. `trim`. Custom sanitizers are represented with `with` kind.
+    Sanitizer { kind: &'static str },
+
+    /// Validator, e.g. `len_char_max = 20`.
+    /// The kind is the same as [`ValidationError::code`](crate::ValidationError::code) of the
+    /// corresponding violation.
+    Validator {
+        kind: &'static str,
+        value: Option<ConstraintValue>,
+    },
+}
+
+/// Value of a validator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConstraintValue {
+    /// Length bound, e.g. `len_char_max = 20`.
+    Length(usize),
+
+    /// Bound of a signed integer, e.g. `greater = -10`.
+    Signed(i128),
+
+    /// Bound of an unsigned integer, e.g. `less = 100`.
+    Unsigned(u128),
+
+    /// Bound of a float, e.g. `greater_or_equal = 0.5`.
+    Float(f64),
+
+    /// Regex pattern, e.g. `regex = "^[0-9]+$"`.
+    Pattern(&'static str),
+
+    /// Text of a validator, e.g. `starts_with = "https://"`.
+    Text(&'static str),
+
+    /// Allowed values, e.g. `one_of = ["EUR", "USD"]`.
+    TextList(&'static [&'static str]),
+
+    /// Source code of a value that cannot be evaluated at compile time,
+    /// e.g. a bound computed with a function call or a regex constant.
+    Expr(&'static str),
+}
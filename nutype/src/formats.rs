@@ -0,0 +1,304 @@
+//! Definitions of the string formats used by the built-in format validators
+//! (`email`, `url`, `uuid`, `ipv4`, `ipv6`, `hex`, `base64`, `semver` and `iso8601_date`).
+//!
+//! The validators call these functions, so they can also be used directly
+//! to check a value without constructing a newtype:
+//!
+//! ```
+//! assert!(nutype::formats::is_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+//! assert!(!nutype::formats::is_semver("1.0"));
+//! ```
+//!
+//! All the checks are pure functions, that work without allocation and in `no_std` environment.
+
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// Checks that the value is an email address, as defined by the
+/// [HTML Standard](https://html.spec.whatwg.org/multipage/input.html#valid-e-mail-address):
+/// a non-empty local part of ASCII letters, digits and ``.!#$%&'*+/=?^_`{|}~-`` characters,
+/// followed by `@` and a domain of one or more labels separated by dots.
+/// Every label has 1-63 ASCII letters, digits or hyphens, and does not start or end with a hyphen.
+///
+/// ```
+/// use nutype::formats::is_email;
+///
+/// assert!(is_email("john.doe+news@example.com"));
+/// assert!(!is_email("john.doe@"));
+/// assert!(!is_email("John Doe <john@example.com>"));
+/// ```
+pub fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    let is_local_char = |c: u8| c.is_ascii_alphanumeric() || b".!#$%&'*+/=?^_`{|}~-".contains(&c);
+    !local.is_empty() && local.bytes().all(is_local_char) && is_domain(domain)
+}
+
+/// Checks that the value is an absolute URL with a host:
+/// `scheme://[userinfo@]host[:port][/path][?query][#fragment]`.
+///
+/// * The scheme starts with an ASCII letter followed by letters, digits, `+`, `-` or `.`.
+/// * The host is a domain name (see [`is_email`] for the rules of the labels),
+///   an IPv4 address or an IPv6 address in brackets.
+/// * The port has 1-5 digits and does not exceed 65535.
+/// * The rest of the URL has no whitespace or control characters.
+///
+/// ```
+/// use nutype::formats::is_url;
+///
+/// assert!(is_url("https://example.com/path?query=1#top"));
+/// assert!(is_url("postgres://user:secret@[::1]:5432/db"));
+/// assert!(!is_url("example.com"));
+/// assert!(!is_url("https://exa mple.com"));
+/// ```
+pub fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+    let is_valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'+' | b'-' | b'.'));
+    if !is_valid_scheme || rest.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return false;
+    }
+
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let authority = &rest[..authority_end];
+    let host_and_port = match authority.rsplit_once('@') {
+        Some((_userinfo, host_and_port)) => host_and_port,
+        None => authority,
+    };
+
+    let (host, maybe_port) = if let Some(bracketed) = host_and_port.strip_prefix('[') {
+        let Some((ipv6, after)) = bracketed.split_once(']') else {
+            return false;
+        };
+        if !is_ipv6(ipv6) {
+            return false;
+        }
+        match after {
+            "" => (ipv6, None),
+            _ => match after.strip_prefix(':') {
+                Some(port) => (ipv6, Some(port)),
+                None => return false,
+            },
+        }
+    } else {
+        let (host, maybe_port) = match host_and_port.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_and_port, None),
+        };
+        if !(is_domain(host) || is_ipv4(host)) {
+            return false;
+        }
+        (host, maybe_port)
+    };
+
+    let is_valid_port = match maybe_port {
+        None => true,
+        Some(port) => {
+            (1..=5).contains(&port.len())
+                && port.bytes().all(|c| c.is_ascii_digit())
+                && port.parse::<u16>().is_ok()
+        }
+    };
+    !host.is_empty() && is_valid_port
+}
+
+/// Checks that the value is a UUID in the hyphenated form
+/// (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`), case-insensitive.
+/// Version and variant bits are not checked.
+///
+/// ```
+/// use nutype::formats::is_uuid;
+///
+/// assert!(is_uuid("67E55044-10B1-426F-9247-BB680E5FE0C8"));
+/// assert!(!is_uuid("67e5504410b1426f9247bb680e5fe0c8"));
+/// ```
+pub fn is_uuid(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 36
+        && bytes.iter().enumerate().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => *c == b'-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// Checks that the value is an IPv4 address in the dotted decimal notation (e.g. `192.168.0.1`),
+/// as accepted by [`Ipv4Addr`]'s `FromStr` (leading zeros are rejected).
+///
+/// ```
+/// use nutype::formats::is_ipv4;
+///
+/// assert!(is_ipv4("192.168.0.1"));
+/// assert!(!is_ipv4("192.168.0.256"));
+/// assert!(!is_ipv4("192.168.0.01"));
+/// ```
+pub fn is_ipv4(value: &str) -> bool {
+    value.parse::<Ipv4Addr>().is_ok()
+}
+
+/// Checks that the value is an IPv6 address in the text representation of
+/// [RFC 4291](https://www.rfc-editor.org/rfc/rfc4291#section-2.2) (e.g. `2001:db8::1`),
+/// as accepted by [`Ipv6Addr`]'s `FromStr`. Zone identifiers (`%eth0`) are not accepted.
+///
+/// ```
+/// use nutype::formats::is_ipv6;
+///
+/// assert!(is_ipv6("2001:db8::1"));
+/// assert!(is_ipv6("::ffff:192.168.0.1"));
+/// assert!(!is_ipv6("2001:db8:::1"));
+/// ```
+pub fn is_ipv6(value: &str) -> bool {
+    value.parse::<Ipv6Addr>().is_ok()
+}
+
+/// Checks that the value is a non-empty sequence of hexadecimal digits, case-insensitive.
+/// Prefixes like `0x` are not accepted.
+///
+/// ```
+/// use nutype::formats::is_hex;
+///
+/// assert!(is_hex("DeadBeef"));
+/// assert!(!is_hex("0xdeadbeef"));
+/// assert!(!is_hex(""));
+/// ```
+pub fn is_hex(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Checks that the value is non-empty Base64 with the standard alphabet and padding
+/// ([RFC 4648, section 4](https://www.rfc-editor.org/rfc/rfc4648#section-4)):
+/// the length is a multiple of 4, and up to two `=` characters are allowed at the end only.
+///
+/// ```
+/// use nutype::formats::is_base64;
+///
+/// assert!(is_base64("bnV0eXBl"));
+/// assert!(is_base64("bnV0eQ=="));
+/// assert!(!is_base64("bnV0eQ"));
+/// assert!(!is_base64("bnV0-Q=="));
+/// ```
+pub fn is_base64(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.is_empty() || !bytes.len().is_multiple_of(4) {
+        return false;
+    }
+    let data = match bytes {
+        [data @ .., b'=', b'='] | [data @ .., b'='] => data,
+        data => data,
+    };
+    data.iter()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'+' | b'/'))
+}
+
+/// Checks that the value is a version as defined by [Semantic Versioning 2.0.0](https://semver.org/):
+/// `MAJOR.MINOR.PATCH[-PRERELEASE][+BUILD]`.
+///
+/// * Numbers have no leading zeros.
+/// * Pre-release and build metadata are non-empty dot separated identifiers of ASCII letters, digits and hyphens.
+/// * Numeric pre-release identifiers have no leading zeros.
+///
+/// ```
+/// use nutype::formats::is_semver;
+///
+/// assert!(is_semver("1.0.0-rc.1+build.5"));
+/// assert!(!is_semver("1.0"));
+/// assert!(!is_semver("01.0.0"));
+/// ```
+pub fn is_semver(value: &str) -> bool {
+    let (version, maybe_build) = match value.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (value, None),
+    };
+    let (core, maybe_pre_release) = match version.split_once('-') {
+        Some((core, pre_release)) => (core, Some(pre_release)),
+        None => (version, None),
+    };
+
+    let mut numbers = core.split('.');
+    let is_valid_core = (0..3).all(|_| numbers.next().is_some_and(is_numeric_identifier))
+        && numbers.next().is_none();
+    let is_valid_pre_release = maybe_pre_release.is_none_or(|pre_release| {
+        pre_release.split('.').all(|identifier| {
+            is_alphanumeric_identifier(identifier)
+                && (!identifier.bytes().all(|c| c.is_ascii_digit())
+                    || is_numeric_identifier(identifier))
+        })
+    });
+    let is_valid_build =
+        maybe_build.is_none_or(|build| build.split('.').all(is_alphanumeric_identifier));
+
+    is_valid_core && is_valid_pre_release && is_valid_build
+}
+
+/// Checks that the value is a calendar date in the ISO 8601 extended format `YYYY-MM-DD`
+/// (e.g. `2024-02-29`). The day must exist in the month, leap years are taken into account.
+///
+/// ```
+/// use nutype::formats::is_iso8601_date;
+///
+/// assert!(is_iso8601_date("2024-02-29"));
+/// assert!(!is_iso8601_date("2023-02-29"));
+/// assert!(!is_iso8601_date("2024-2-9"));
+/// ```
+pub fn is_iso8601_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let is_valid_shape = bytes.len() == 10
+        && bytes.iter().enumerate().all(|(index, c)| match index {
+            4 | 7 => *c == b'-',
+            _ => c.is_ascii_digit(),
+        });
+    if !is_valid_shape {
+        return false;
+    }
+
+    let number = |range: core::ops::Range<usize>| {
+        bytes[range]
+            .iter()
+            .fold(0u32, |acc, c| acc * 10 + u32::from(c - b'0'))
+    };
+    let year = number(0..4);
+    let month = number(5..7);
+    let day = number(8..10);
+    (1..=days_in_month(year, month)).contains(&day)
+}
+
+/// Number of days in the month of the year, `0` for invalid months.
+const fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 0,
+    }
+}
+
+fn is_domain(value: &str) -> bool {
+    !value.is_empty()
+        && value.split('.').all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        })
+}
+
+fn is_numeric_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value.bytes().all(|c| c.is_ascii_digit())
+        && (value == "0" || !value.starts_with('0'))
+}
+
+fn is_alphanumeric_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+}
@@ -0,0 +1,269 @@
+//! Tables of the codes used by the built-in validators `iso3166_alpha2`, `iso3166_alpha3`,
+//! `iso4217` and `bcp47`.
+//!
+//! The validators call these functions, so they can also be used directly
+//! to check a value without constructing a newtype:
+//!
+//! ```
+//! assert!(nutype::iso_codes::is_iso3166_alpha2("UA"));
+//! assert!(!nutype::iso_codes::is_iso3166_alpha2("ZZ"));
+//! assert!(nutype::iso_codes::is_bcp47("zh-Hant-TW"));
+//! ```
+//!
+//! The tables reflect the officially assigned codes as of 2025. They are sorted,
+//! so they can be searched with `binary_search`.
+
+/// Officially assigned ISO 3166-1 alpha-2 country codes (e.g. `"DE"`), sorted.
+pub const ISO3166_ALPHA2: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// Officially assigned ISO 3166-1 alpha-3 country codes (e.g. `"DEU"`), sorted.
+pub const ISO3166_ALPHA3: &[&str] = &[
+    "ABW", "AFG", "AGO", "AIA", "ALA", "ALB", "AND", "ARE", "ARG", "ARM", "ASM", "ATA", "ATF",
+    "ATG", "AUS", "AUT", "AZE", "BDI", "BEL", "BEN", "BES", "BFA", "BGD", "BGR", "BHR", "BHS",
+    "BIH", "BLM", "BLR", "BLZ", "BMU", "BOL", "BRA", "BRB", "BRN", "BTN", "BVT", "BWA", "CAF",
+    "CAN", "CCK", "CHE", "CHL", "CHN", "CIV", "CMR", "COD", "COG", "COK", "COL", "COM", "CPV",
+    "CRI", "CUB", "CUW", "CXR", "CYM", "CYP", "CZE", "DEU", "DJI", "DMA", "DNK", "DOM", "DZA",
+    "ECU", "EGY", "ERI", "ESH", "ESP", "EST", "ETH", "FIN", "FJI", "FLK", "FRA", "FRO", "FSM",
+    "GAB", "GBR", "GEO", "GGY", "GHA", "GIB", "GIN", "GLP", "GMB", "GNB", "GNQ", "GRC", "GRD",
+    "GRL", "GTM", "GUF", "GUM", "GUY", "HKG", "HMD", "HND", "HRV", "HTI", "HUN", "IDN", "IMN",
+    "IND", "IOT", "IRL", "IRN", "IRQ", "ISL", "ISR", "ITA", "JAM", "JEY", "JOR", "JPN", "KAZ",
+    "KEN", "KGZ", "KHM", "KIR", "KNA", "KOR", "KWT", "LAO", "LBN", "LBR", "LBY", "LCA", "LIE",
+    "LKA", "LSO", "LTU", "LUX", "LVA", "MAC", "MAF", "MAR", "MCO", "MDA", "MDG", "MDV", "MEX",
+    "MHL", "MKD", "MLI", "MLT", "MMR", "MNE", "MNG", "MNP", "MOZ", "MRT", "MSR", "MTQ", "MUS",
+    "MWI", "MYS", "MYT", "NAM", "NCL", "NER", "NFK", "NGA", "NIC", "NIU", "NLD", "NOR", "NPL",
+    "NRU", "NZL", "OMN", "PAK", "PAN", "PCN", "PER", "PHL", "PLW", "PNG", "POL", "PRI", "PRK",
+    "PRT", "PRY", "PSE", "PYF", "QAT", "REU", "ROU", "RUS", "RWA", "SAU", "SDN", "SEN", "SGP",
+    "SGS", "SHN", "SJM", "SLB", "SLE", "SLV", "SMR", "SOM", "SPM", "SRB", "SSD", "STP", "SUR",
+    "SVK", "SVN", "SWE", "SWZ", "SXM", "SYC", "SYR", "TCA", "TCD", "TGO", "THA", "TJK", "TKL",
+    "TKM", "TLS", "TON", "TTO", "TUN", "TUR", "TUV", "TWN", "TZA", "UGA", "UKR", "UMI", "URY",
+    "USA", "UZB", "VAT", "VCT", "VEN", "VGB", "VIR", "VNM", "VUT", "WLF", "WSM", "YEM", "ZAF",
+    "ZMB", "ZWE",
+];
+
+/// Active ISO 4217 currency codes (e.g. `"EUR"`), including funds and precious metals, sorted.
+pub const ISO4217: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN",
+    "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD",
+    "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUP", "CVE", "CZK",
+    "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS",
+    "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD",
+    "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD",
+    "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT",
+    "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK",
+    "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD",
+    "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SOS", "SRD", "SSP",
+    "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS",
+    "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES", "VND", "VUV", "WST",
+    "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR", "XOF", "XPD", "XPF",
+    "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+/// ISO 639-1 two-letter language codes (e.g. `"en"`), sorted.
+pub const ISO639_1: &[&str] = &[
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bi",
+    "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da", "de",
+    "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr", "fy",
+    "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz", "ia",
+    "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj", "kk",
+    "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln", "lo",
+    "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb", "nd",
+    "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi", "pl",
+    "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk", "sl",
+    "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti", "tk",
+    "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo", "wa",
+    "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+const _: () = assert!(
+    is_sorted(ISO3166_ALPHA2)
+        && is_sorted(ISO3166_ALPHA3)
+        && is_sorted(ISO4217)
+        && is_sorted(ISO639_1),
+    "The tables must be sorted to be searched with `binary_search`"
+);
+
+/// Checks that the value is an officially assigned ISO 3166-1 alpha-2 country code in uppercase.
+///
+/// ```
+/// use nutype::iso_codes::is_iso3166_alpha2;
+///
+/// assert!(is_iso3166_alpha2("DE"));
+/// assert!(!is_iso3166_alpha2("de"));
+/// assert!(!is_iso3166_alpha2("ZZ"));
+/// ```
+pub fn is_iso3166_alpha2(value: &str) -> bool {
+    ISO3166_ALPHA2.binary_search(&value).is_ok()
+}
+
+/// Checks that the value is an officially assigned ISO 3166-1 alpha-3 country code in uppercase.
+///
+/// ```
+/// use nutype::iso_codes::is_iso3166_alpha3;
+///
+/// assert!(is_iso3166_alpha3("DEU"));
+/// assert!(!is_iso3166_alpha3("deu"));
+/// assert!(!is_iso3166_alpha3("ZZZ"));
+/// ```
+pub fn is_iso3166_alpha3(value: &str) -> bool {
+    ISO3166_ALPHA3.binary_search(&value).is_ok()
+}
+
+/// Checks that the value is an active ISO 4217 currency code in uppercase.
+///
+/// ```
+/// use nutype::iso_codes::is_iso4217;
+///
+/// assert!(is_iso4217("EUR"));
+/// assert!(!is_iso4217("eur"));
+/// assert!(!is_iso4217("HRK"));
+/// ```
+pub fn is_iso4217(value: &str) -> bool {
+    ISO4217.binary_search(&value).is_ok()
+}
+
+/// Checks that the value is a well-formed [BCP 47](https://www.rfc-editor.org/rfc/rfc5646) language tag:
+/// `language[-extlang][-script][-region][-variant...][-extension...][-x-privateuse]`, or a private use tag (`x-...`).
+///
+/// * A two-letter language subtag must be an ISO 639-1 code.
+/// * A two-letter region subtag must be an ISO 3166-1 alpha-2 code.
+/// * The other subtags (e.g. three-letter languages, scripts or UN M.49 regions) are checked for well-formedness only.
+///
+/// The tags are case-insensitive, though the canonical form is `en-US` or `zh-Hant-TW`.
+/// Grandfathered tags (e.g. `i-klingon`) are not accepted.
+///
+/// ```
+/// use nutype::iso_codes::is_bcp47;
+///
+/// assert!(is_bcp47("en"));
+/// assert!(is_bcp47("en-US"));
+/// assert!(is_bcp47("es-419"));
+/// assert!(is_bcp47("sr-Latn-RS"));
+/// assert!(is_bcp47("de-CH-1996"));
+/// assert!(!is_bcp47("en-ZZ"));
+/// assert!(!is_bcp47("qq-US"));
+/// assert!(!is_bcp47("en_US"));
+/// ```
+pub fn is_bcp47(value: &str) -> bool {
+    let mut subtags = value.split('-').peekable();
+    let language = subtags.next().unwrap_or_default();
+    if language.eq_ignore_ascii_case("x") {
+        return is_private_use(subtags);
+    }
+    let is_valid_language = match language.len() {
+        2 => contains_ignore_case(ISO639_1, language, u8::to_ascii_lowercase),
+        3 | 5..=8 => language.bytes().all(|c| c.is_ascii_alphabetic()),
+        _ => false,
+    };
+    if !is_valid_language {
+        return false;
+    }
+
+    // Up to three extended language subtags, e.g. `zh-yue`.
+    let is_extlang = |s: &&str| s.len() == 3 && s.bytes().all(|c| c.is_ascii_alphabetic());
+    let mut extlang_count = 0;
+    while subtags.next_if(is_extlang).is_some() {
+        extlang_count += 1;
+    }
+    if extlang_count > 3 || (extlang_count > 0 && language.len() > 3) {
+        return false;
+    }
+    // Script, e.g. `Latn`.
+    subtags.next_if(|s| s.len() == 4 && s.bytes().all(|c| c.is_ascii_alphabetic()));
+    // Region, e.g. `US` or `419`.
+    subtags.next_if(|s| match s.len() {
+        2 => contains_ignore_case(ISO3166_ALPHA2, s, u8::to_ascii_uppercase),
+        3 => s.bytes().all(|c| c.is_ascii_digit()),
+        _ => false,
+    });
+    // Variants, e.g. `1996` or `rozaj`.
+    while subtags.next_if(is_variant).is_some() {}
+
+    // Extensions, e.g. `u-ca-buddhist`, followed by an optional private use part.
+    loop {
+        match subtags.next() {
+            None => return true,
+            Some(singleton) if singleton.eq_ignore_ascii_case("x") => {
+                return is_private_use(subtags);
+            }
+            Some(singleton)
+                if singleton.len() == 1 && singleton.bytes().all(|c| c.is_ascii_alphanumeric()) =>
+            {
+                let is_extension_subtag = |s: &&str| {
+                    (2..=8).contains(&s.len()) && s.bytes().all(|c| c.is_ascii_alphanumeric())
+                };
+                if subtags.next_if(is_extension_subtag).is_none() {
+                    return false;
+                }
+                while subtags.next_if(is_extension_subtag).is_some() {}
+            }
+            Some(_) => return false,
+        }
+    }
+}
+
+fn is_variant(subtag: &&str) -> bool {
+    let bytes = subtag.as_bytes();
+    let is_alphanumeric = bytes.iter().all(u8::is_ascii_alphanumeric);
+    match bytes.len() {
+        4 => is_alphanumeric && bytes[0].is_ascii_digit(),
+        5..=8 => is_alphanumeric,
+        _ => false,
+    }
+}
+
+fn is_private_use<'a>(subtags: impl Iterator<Item = &'a str>) -> bool {
+    let mut count = 0;
+    for subtag in subtags {
+        if !(1..=8).contains(&subtag.len()) || !subtag.bytes().all(|c| c.is_ascii_alphanumeric()) {
+            return false;
+        }
+        count += 1;
+    }
+    count > 0
+}
+
+/// Looks up the value in the table, converting the value to the case of the table first.
+fn contains_ignore_case(table: &[&str], value: &str, to_case: fn(&u8) -> u8) -> bool {
+    table
+        .binary_search_by(|code| code.bytes().cmp(value.as_bytes().iter().map(to_case)))
+        .is_ok()
+}
+
+const fn is_sorted(table: &[&str]) -> bool {
+    let mut index = 1;
+    while index < table.len() {
+        if !is_less(table[index - 1].as_bytes(), table[index].as_bytes()) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+const fn is_less(left: &[u8], right: &[u8]) -> bool {
+    let mut index = 0;
+    while index < left.len() && index < right.len() {
+        if left[index] != right[index] {
+            return left[index] < right[index];
+        }
+        index += 1;
+    }
+    left.len() < right.len()
+}
@@ -101,31 +101,215 @@
 //!
 //! ### String sanitizers
 //!
-//! | Sanitizer   | Description                                                                         | Example                                         |
-//! |-------------|-------------------------------------------------------------------------------------|-------------------------------------------------|
-//! | `trim`      | Removes leading and trailing whitespaces                                            | `trim`                                          |
-//! | `lowercase` | Converts the string to lowercase                                                    | `lowercase`                                     |
-//! | `uppercase` | Converts the string to uppercase                                                    | `uppercase`                                     |
-//! | `with`      | Custom sanitizer. A function or closure that receives `String` and returns `String` | `with = \|mut s: String\| { s.truncate(5); s }` |
+//! | Sanitizer             | Description                                                                                                             | Example                                   |
+//! |-----------------------|-------------------------------------------------------------------------------------------------------------------------|-------------------------------------------|
+//! | `trim`                | Removes leading and trailing whitespaces                                                                                | `trim`                                    |
+//! | `trim_start`          | Removes leading whitespaces                                                                                             | `trim_start`                              |
+//! | `trim_end`            | Removes trailing whitespaces                                                                                            | `trim_end`                                |
+//! | `lowercase`           | Converts the string to lowercase                                                                                        | `lowercase`                               |
+//! | `uppercase`           | Converts the string to uppercase                                                                                        | `uppercase`                               |
+//! | `remove_whitespace`   | Removes all whitespaces, including the ones in the middle (e.g. `"DE89 3704"` -> `"DE893704"`)                          | `remove_whitespace`                       |
+//! | `collapse_whitespace` | Replaces every run of whitespaces with a single space (e.g. `"a \t\n b"` -> `"a b"`)                                    | `collapse_whitespace`                     |
+//! | `strip_control_chars` | Removes control characters and zero-width characters (e.g. `\u{200B}`, `\u{FEFF}`)                                      | `strip_control_chars`                     |
+//! | `truncate`            | Keeps the first N chars. `truncate(len = N, graphemes)` keeps N grapheme clusters (requires `unicode` feature)          | `truncate = 255`                          |
+//! | `replace`             | Replaces all occurrences of a substring. Can be used multiple times                                                     | `replace(from = "\t", to = " ")`          |
+//! | `pad_start`           | Prepends the char until the string is N chars long                                                                      | `pad_start(len = 10, char = '0')`         |
+//! | `nfc`                 | Normalizes the string to Unicode Normalization Form C (composed). Requires `unicode` feature.                           | `nfc`                                     |
+//! | `nfd`                 | Normalizes the string to Unicode Normalization Form D (decomposed). Requires `unicode` feature.                         | `nfd`                                     |
+//! | `nfkc`                | Normalizes the string to Unicode Normalization Form KC (e.g. `"ﬁ"` -> `"fi"`). Requires `unicode` feature.              | `nfkc`                                    |
+//! | `nfkd`                | Normalizes the string to Unicode Normalization Form KD. Requires `unicode` feature.                                     | `nfkd`                                    |
+//! | `casefold`            | Applies Unicode case folding for caseless matching (e.g. `"Straße"` -> `"strasse"`). Requires `unicode` feature.        | `casefold`                                |
+//! | `slugify`             | Transliterates to ASCII and joins lowercase words with `-` (e.g. `"Ça va?"` -> `"ca-va"`). Requires `unicode` feature.  | `slugify` or `slugify(separator = '_')`   |
+//! | `with`                | Custom sanitizer. A function or closure that receives `String` and returns `String`, or modifies `&mut String` in place | `with = \|s: &mut String\| s.truncate(5)` |
+//!
+//! Normalization and case folding make the values, that differ only in the composition of characters
+//! or their case, equal. E.g. usernames `"José"` and `"JOSE\u{301}"` both become `"josé"`:
+//!
+//! ```
+//! # #[cfg(feature = "unicode")] {
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(nfc, casefold), derive(Debug, PartialEq))]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Username::new("José"), Username::new("JOSE\u{301}"));
+//! # }
+//! ```
+//!
+//! Sanitizers `lowercase`, `uppercase` and `casefold` cannot be combined, neither can the normalization forms.
+//!
+//! The lengths of `truncate` and `pad_start` are checked against `len_char_min` and `len_char_max` validators at compile time,
+//! so e.g. `truncate = 300` together with `len_char_max = 255` is rejected.
+//!
+//! `slugify` turns any text into a value of `slug` validator, so they are usually combined (with the same `separator`).
+//! A text without letters and digits becomes empty and is rejected by `slug`.
+//! `derive(Arbitrary)` generates valid slugs and identifiers within the length bounds:
+//!
+//! ```
+//! # #[cfg(feature = "unicode")] {
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(slugify), validate(slug, len_char_max = 64), derive(Debug, PartialEq))]
+//! pub struct ArticleSlug(String);
+//!
+//! assert_eq!(ArticleSlug::try_new("Hello, Wörld!").unwrap().into_inner(), "hello-world");
+//! assert_eq!(ArticleSlug::try_new("?!"), Err(ArticleSlugError::SlugViolated));
+//! # }
+//! ```
 //!
 //! ### String validators
 //!
-//! | Validator       | Description                                                                     | Error variant         | Example                                      |
-//! |-----------------|---------------------------------------------------------------------------------|-----------------------|----------------------------------------------|
-//! | `len_char_min`  | Min length of the string (in chars, not bytes)                                  | `LenCharMinViolated`  | `len_char_min = 5`                           |
-//! | `len_char_max`  | Max length of the string (in chars, not bytes)                                  | `LenCharMaxViolated`  | `len_char_max = 255`                         |
-//! | `len_utf16_min` | Min length of the string in UTF-16 code units (useful for JavaScript interop)   | `LenUtf16MinViolated` | `len_utf16_min = 5`                          |
-//! | `len_utf16_max` | Max length of the string in UTF-16 code units (useful for JavaScript interop)   | `LenUtf16MaxViolated` | `len_utf16_max = 255`                        |
-//! | `not_empty`     | Rejects an empty string                                                         | `NotEmptyViolated`    | `not_empty`                                  |
-//! | `regex`         | Validates format with a regex. Requires `regex` feature.                        | `RegexViolated`       | `regex = "^[0-9]{7}$"` or `regex = ID_REGEX` |
-//! | `predicate`     | Custom validator. A function or closure that receives `&str` and returns `bool` | `PredicateViolated`   | `predicate = \|s: &str\| s.contains('@')`    |
-//! | `with`          | Custom validator with a custom error                                            | N/A                   | (see example below)                          |
+//! | Validator          | Description                                                                     | Error variant            | Example                                      |
+//! |--------------------|---------------------------------------------------------------------------------|--------------------------|----------------------------------------------|
+//! | `len_char_min`     | Min length of the string (in chars, not bytes)                                  | `LenCharMinViolated`     | `len_char_min = 5`                           |
+//! | `len_char_max`     | Max length of the string (in chars, not bytes)                                  | `LenCharMaxViolated`     | `len_char_max = 255`                         |
+//! | `len_utf16_min`    | Min length of the string in UTF-16 code units (useful for JavaScript interop)   | `LenUtf16MinViolated`    | `len_utf16_min = 5`                          |
+//! | `len_utf16_max`    | Max length of the string in UTF-16 code units (useful for JavaScript interop)   | `LenUtf16MaxViolated`    | `len_utf16_max = 255`                        |
+//! | `len_bytes_min`    | Min length of the string in bytes (UTF-8)                                       | `LenBytesMinViolated`    | `len_bytes_min = 5`                          |
+//! | `len_bytes_max`    | Max length of the string in bytes (UTF-8), e.g. to fit a storage column         | `LenBytesMaxViolated`    | `len_bytes_max = 255`                        |
+//! | `len_grapheme_min` | Min length in grapheme clusters. Requires `unicode` feature.                    | `LenGraphemeMinViolated` | `len_grapheme_min = 2`                       |
+//! | `len_grapheme_max` | Max length in grapheme clusters. Requires `unicode` feature.                    | `LenGraphemeMaxViolated` | `len_grapheme_max = 32`                      |
+//! | `not_empty`        | Rejects an empty string                                                         | `NotEmptyViolated`       | `not_empty`                                  |
+//! | `ascii`            | Accepts only ASCII characters                                                   | `AsciiViolated`          | `ascii`                                      |
+//! | `alphanumeric`     | Accepts only letters and digits (in terms of Unicode)                           | `AlphanumericViolated`   | `alphanumeric`                               |
+//! | `printable`        | Rejects control characters and whitespaces other than the regular space         | `PrintableViolated`      | `printable`                                  |
+//! | `no_control_chars` | Rejects control characters (e.g. `\0`, `\t`, `\n`)                              | `NoControlCharsViolated` | `no_control_chars`                           |
+//! | `starts_with`      | Requires the given prefix                                                       | `StartsWithViolated`     | `starts_with = "https://"`                   |
+//! | `ends_with`        | Requires the given suffix                                                       | `EndsWithViolated`       | `ends_with = ".pdf"`                         |
+//! | `contains`         | Requires the given substring                                                    | `ContainsViolated`       | `contains = "@"`                             |
+//! | `not_contains`     | Rejects the given substring                                                     | `NotContainsViolated`    | `not_contains = ".."`                        |
+//! | `one_of`           | Accepts only the listed values                                                  | `OneOfViolated`          | `one_of = ["EUR", "USD"]`                    |
+//! | `slug`             | Lowercase ASCII letters and digits, the words joined with a single `-`          | `SlugViolated`           | `slug` or `slug(separator = '_')`            |
+//! | `identifier`       | ASCII letters, digits and `_`, not starting with a digit (like in Rust or C)    | `IdentifierViolated`     | `identifier`                                 |
+//! | `email`            | Email address (HTML Standard definition). Requires `formats` feature.           | `EmailViolated`          | `email`                                      |
+//! | `url`              | Absolute URL with a host. Requires `formats` feature.                           | `UrlViolated`            | `url`                                        |
+//! | `uuid`             | Hyphenated UUID, case-insensitive. Requires `formats` feature.                  | `UuidViolated`           | `uuid`                                       |
+//! | `ipv4`             | IPv4 address. Requires `formats` feature.                                       | `Ipv4Violated`           | `ipv4`                                       |
+//! | `ipv6`             | IPv6 address. Requires `formats` feature.                                       | `Ipv6Violated`           | `ipv6`                                       |
+//! | `hex`              | Non-empty hexadecimal string. Requires `formats` feature.                       | `HexViolated`            | `hex`                                        |
+//! | `base64`           | Base64 with the standard alphabet and padding. Requires `formats` feature.      | `Base64Violated`         | `base64`                                     |
+//! | `semver`           | Semantic version 2.0.0. Requires `formats` feature.                             | `SemverViolated`         | `semver`                                     |
+//! | `iso8601_date`     | Calendar date `YYYY-MM-DD`. Requires `formats` feature.                         | `Iso8601DateViolated`    | `iso8601_date`                               |
+//! | `luhn`             | Digits with a valid Luhn check digit.                                           | `LuhnViolated`           | `luhn`                                       |
+//! | `iban`             | IBAN with valid check digits.                                                   | `IbanViolated`           | `iban`                                       |
+//! | `isbn`             | ISBN-10 or ISBN-13 with a valid check digit.                                    | `IsbnViolated`           | `isbn`                                       |
+//! | `ean`              | EAN-8 or EAN-13 with a valid check digit.                                       | `EanViolated`            | `ean`                                        |
+//! | `iso3166_alpha2`   | ISO 3166-1 alpha-2 country code, uppercase. Requires `iso_codes` feature.       | `Iso3166Alpha2Violated`  | `iso3166_alpha2`                             |
+//! | `iso3166_alpha3`   | ISO 3166-1 alpha-3 country code, uppercase. Requires `iso_codes` feature.       | `Iso3166Alpha3Violated`  | `iso3166_alpha3`                             |
+//! | `iso4217`          | ISO 4217 currency code, uppercase. Requires `iso_codes` feature.                | `Iso4217Violated`        | `iso4217`                                    |
+//! | `bcp47`            | BCP 47 language tag, e.g. `en-US`. Requires `iso_codes` feature.                | `Bcp47Violated`          | `bcp47`                                      |
+//! | `regex`            | Validates format with a regex. Requires `regex` feature.                        | `RegexViolated`          | `regex = "^[0-9]{7}$"` or `regex = ID_REGEX` |
+//! | `not_regex`        | Rejects the values that match a regex. Requires `regex` feature.                | `NotRegexViolated`       | `not_regex = "\\s"`                          |
+//! | `predicate`        | Custom validator. A function or closure that receives `&str` and returns `bool` | `PredicateViolated`      | `predicate = \|s: &str\| s.contains('@')`    |
+//! | `with`             | Custom validator with a custom error                                            | N/A                      | (see example below)                          |
+//!
+//! #### Text validators
+//!
+//! The literals of `starts_with`, `ends_with`, `contains`, `not_contains` and `one_of` are checked at compile time
+//! against the other validators and sanitizers, so contradictions (e.g. a `one_of` value longer than `len_char_max`,
+//! or a value that `lowercase` sanitizer would change) are reported as compile errors.
+//!
+//! Every value of `one_of` is also exposed as an associated constant, named after the value in upper snake case
+//! (values without ASCII letters or digits get no constant; values that map to the same name are rejected):
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(one_of = ["EUR", "USD", "en-US"]),
+//!     derive(Debug, PartialEq)
+//! )]
+//! pub struct Code(String);
+//!
+//! assert_eq!(Code::USD, "USD");
+//! assert_eq!(Code::EN_US, "en-US");
+//! assert_eq!(Code::try_new(" EUR ").unwrap().into_inner(), "EUR");
+//! assert_eq!(Code::try_new("GBP"), Err(CodeError::OneOfViolated));
+//! ```
+//!
+//! #### Format validators
+//!
+//! With `formats` feature enabled, the common string formats can be validated without regexes:
+//! `email`, `url`, `uuid`, `ipv4`, `ipv6`, `hex`, `base64`, `semver` and `iso8601_date`.
+//! The exact definition of every format is documented in `nutype::formats` module, which also exposes the check functions (e.g. `nutype::formats::is_email`).
+//!
+//! ```
+//! # #[cfg(feature = "formats")] {
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim, lowercase),
+//!     validate(len_char_max = 254, email),
+//!     derive(Debug, PartialEq)
+//! )]
+//! pub struct Email(String);
+//!
+//! assert_eq!(
+//!     Email::try_new(" John@Example.com ").unwrap().into_inner(),
+//!     "john@example.com"
+//! );
+//! assert_eq!(Email::try_new("john@"), Err(EmailError::EmailViolated));
+//! # }
+//! ```
+//!
+//! `derive(Arbitrary)` generates valid values of the format, as long as the format validator is combined only with `not_empty`.
+//!
+//! #### Checksum validators
+//!
+//! Identifiers with check digits can be validated without any feature:
+//! `luhn` (e.g. payment card numbers), `iban`, `isbn` and `ean`. Integer types support `luhn` too.
+//! The values are expected without separators, so use `remove_whitespace` and `uppercase` sanitizers to normalize the input first.
+//! The check functions are exposed by `nutype::checksums` module (e.g. `nutype::checksums::is_luhn`).
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(remove_whitespace, uppercase),
+//!     validate(iban),
+//!     derive(Debug, PartialEq)
+//! )]
+//! pub struct Iban(String);
+//!
+//! assert_eq!(
+//!     Iban::try_new("de89 3704 0044 0532 0130 00").unwrap().into_inner(),
+//!     "DE89370400440532013000"
+//! );
+//! assert_eq!(Iban::try_new("DE88370400440532013000"), Err(IbanError::IbanViolated));
+//! ```
+//!
+//! #### ISO code validators
+//!
+//! With `iso_codes` feature enabled, the codes can be checked against the tables embedded into `nutype::iso_codes` module:
+//! `iso3166_alpha2` and `iso3166_alpha3` (countries), `iso4217` (currencies) and `bcp47` (language tags).
+//! Country and currency codes are accepted in uppercase only, so combine them with `uppercase` sanitizer to accept user input in any case
+//! (`lowercase` sanitizer is rejected at compile time). BCP 47 language tags are case-insensitive.
+//!
+//! ```
+//! # #[cfg(feature = "iso_codes")] {
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim, uppercase),
+//!     validate(iso3166_alpha2),
+//!     derive(Debug, PartialEq)
+//! )]
+//! pub struct CountryCode(String);
+//!
+//! assert_eq!(CountryCode::try_new("ua").unwrap().into_inner(), "UA");
+//! assert_eq!(CountryCode::try_new("ZZ"), Err(CountryCodeError::Iso3166Alpha2Violated));
+//! # }
+//! ```
+//!
+//! `derive(Arbitrary)` picks the values from the tables.
 //!
 //! #### Regex validation
 //!
 //! Requirements:
-//! * `regex` feature of `nutype` is enabled.
-//! * You crate have to explicitly include `regex` as a dependency.
+//! * `regex` feature of `nutype` is enabled (or `regex_lite`, see [Regex engines](#regex-engines)).
+//!
+//! The engine is re-exported as `nutype::regex`, so your crate does not need to depend on `regex` explicitly.
 //!
 //! There are a number of ways you can use regex.
 //!
@@ -149,7 +333,7 @@
 //!
 //! use nutype::nutype;
 //! use std::sync::LazyLock;
-//! use regex::Regex;
+//! use nutype::regex::Regex;
 //!
 //! static PHONE_NUMBER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new("^[0-9]{3}-[0-9]{3}$").unwrap());
 //!
@@ -196,6 +380,118 @@
 //! # }
 //! ```
 //!
+//! `regex` can be used multiple times, if every rule has its own error variant, so it's known which rule is violated.
+//! `not_regex` rejects the values that match the pattern. Without `variant` the errors are `RegexViolated` and `NotRegexViolated`:
+//!
+//! ```
+//! # #[cfg(feature = "regex")] {
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(
+//!         len_char_min = 8,
+//!         regex(pattern = "[0-9]", variant = MissingDigit),
+//!         regex(pattern = "[A-Z]", variant = MissingUppercase),
+//!         not_regex(pattern = r"\s", variant = ContainsWhitespace),
+//!     ),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Password(String);
+//!
+//! assert!(Password::try_new("Secret123").is_ok());
+//! assert_eq!(Password::try_new("secret123"), Err(PasswordError::MissingUppercase));
+//! assert_eq!(Password::try_new("Secret 123"), Err(PasswordError::ContainsWhitespace));
+//! # }
+//! ```
+//!
+//! With `captures` flag the named capture groups of a regex literal are turned into accessor methods.
+//! A group, that may not participate in a match (e.g. an optional one), returns `Option<&str>`:
+//!
+//! ```
+//! # #[cfg(feature = "regex")] {
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(regex(
+//!         pattern = r"^(?P<area>[0-9]{3})-(?P<number>[0-9]{4})(?: x(?P<ext>[0-9]+))?$",
+//!         captures,
+//!     )),
+//!     derive(Debug),
+//! )]
+//! pub struct PhoneNumber(String);
+//!
+//! let phone = PhoneNumber::try_new("555-1234 x42").unwrap();
+//! assert_eq!(phone.area(), "555");
+//! assert_eq!(phone.number(), "1234");
+//! assert_eq!(phone.ext(), Some("42"));
+//! # }
+//! ```
+//!
+//! The accessors have the visibility of the type. They share the regex with the validation, and it is run again
+//! on every call of an accessor.
+//! Group names, that are keywords, get raw identifiers, e.g. `r#type()`. Other names must be valid identifiers,
+//! that are unique and do not clash with the methods generated by nutype.
+//! `captures` cannot be combined with `dfa` and `bytes` flags.
+//!
+//! `derive(Arbitrary)` generates values from the syntax tree of a regex literal, so they match the regex and satisfy
+//! `len_char_min`, `len_char_max` and `not_empty` validators. Other validators, regexes given by path and word boundaries (`\b`) are not supported.
+//! If the type has sanitizers, the generated values are validated and regenerated when needed.
+//!
+//!//! #### Regex engines
+//!
+//! The engine is picked with the features:
+//! * `regex` - the [regex](https://crates.io/crates/regex) crate. It works in `no_std` environment with `alloc` too.
+//! * `regex_lite` - the [regex-lite](https://crates.io/crates/regex-lite) crate, re-exported as `nutype::regex_lite`. It requires `std` and does not support Unicode classes like `\p{L}`.
+//!
+//! If both of them are enabled (e.g. by different crates in the dependency tree), `regex` takes precedence over `regex_lite`.
+//! A regex given as a literal is validated at compile time with the same engine
+//! and compiled once, on its first use (with `once_cell` in `no_std` environment).
+//! A regex given by path is used as is, so it can be of any engine, e.g. `regex_lite::Regex`.
+//!
+//! With `regex` feature a rule with `bytes` flag matches the bytes of the value with `regex::bytes::Regex`,
+//! so the pattern can match arbitrary bytes with `(?-u)` flag. A regex given by path must be `regex::bytes::Regex` then:
+//!
+//! ```rs
+//! #[nutype(validate(regex(pattern = r"^(?-u:[\x00-\x7F])+$", bytes)), derive(Debug))]
+//! pub struct Ascii(String);
+//! ```
+//!//!
+//! With `regex_dfa` feature a regex literal with `dfa` flag is compiled to a dense DFA at build time.
+//! The DFA is embedded into the binary, so there is neither compilation at runtime nor a lazy static,
+//! which matters when the cold start dominates (e.g. CLIs and serverless functions):
+//!
+//! ```
+//! # #[cfg(feature = "regex_dfa")] {
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(regex(pattern = "^[0-9]{3}-[0-9]{4}$", dfa)), derive(Debug))]
+//! pub struct PhoneNumber(String);
+//!
+//! assert!(PhoneNumber::try_new("555-1234").is_ok());
+//! # }
+//! ```
+//!
+//! DFAs do not support Unicode word boundaries, so use `(?-u:\b)` instead of `\b`.
+//! Note that Unicode classes like `\w` make the DFA, and so the binary, considerably larger than ASCII ones like `[0-9a-z]`.
+//!
+//!
+//! ### Validation before allocation
+//!
+//! String types with validation get `try_from_str(&str)` constructor.
+//! If the type has no sanitizers or only `trim`, `trim_start` and `trim_end`, the borrowed input is validated first and `String` is allocated only
+//! for valid input, which is useful on hot paths that reject most of the input.
+//! Other sanitizers require an owned `String`, so in that case `try_from_str()` is equivalent to `try_new()`.
+//! `FromStr` implementation uses `try_from_str()`.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(trim), validate(len_char_max = 8), derive(Debug))]
+//! pub struct HeaderName(String);
+//!
+//! assert_eq!(HeaderName::try_from_str(" Accept ").unwrap().into_inner(), "Accept");
+//! assert!(HeaderName::try_from_str("X-Very-Long-Header").is_err());
+//! ```
 //!
 //! ### String derivable traits
 //!
@@ -222,6 +518,7 @@
 //! | `less_or_equal`     | Inclusive upper bound                 | `LessOrEqualViolated`     | `less_or_equal = 99`                 |
 //! | `greater`           | Exclusive lower bound                 | `GreaterViolated`         | `greater = 17`                       |
 //! | `greater_or_equal`  | Inclusive lower bound                 | `GreaterOrEqualViolated`  | `greater_or_equal = 18`              |
+//! | `luhn`              | Luhn check digit                      | `LuhnViolated`            | `luhn`                               |
 //! | `predicate`         | Custom predicate                      | `PredicateViolated`       | `predicate = \|num\| num % 2 == 0`   |
 //! | `with`              | Custom validator with a custom error  | N/A                       | (see example below)                  |
 //!
@@ -373,6 +670,22 @@
 //! assert_eq!(city.into_inner(), "Old York");
 //! ```
 //!
+//! For string types a custom sanitizer can also take `&mut String` and modify the value in place,
+//! which avoids moving the string in and out of the function:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(trim, with = |s: &mut String| s.truncate(5)))]
+//! struct Code(String);
+//!
+//! assert_eq!(Code::new("  abcdefgh ").into_inner(), "abcde");
+//! ```
+//!
+//! Built-in string sanitizers work in place as well. Consecutive `trim`, `trim_start`, `trim_end`, `lowercase`, `uppercase`,
+//! `remove_whitespace`, `collapse_whitespace` and `strip_control_chars` are fused into a single pass over the chars,
+//! which rewrites the string within its own buffer, so it's reallocated only if it grows (e.g. `İ` is lowercased into two chars).
+//!
 //! ## Custom validation with predicate
 //!
 //! In similar fashion it's possible to define custom validators, but a validation function receives a reference and returns `bool`.
@@ -433,6 +746,178 @@
 //!
 //! It's important to ensure that the type specified in the `error` attribute matches the error type returned by the validation function.
 //!
+//! ## Collecting all violations
+//!
+//! By default validation stops at the first violated rule. With `report = all` every rule is checked
+//! and `try_new()` returns `<Type>Errors`, a collection of all the violations:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(len_char_min = 5, predicate = |s| s.chars().all(char::is_alphanumeric), report = all),
+//!     derive(Debug),
+//! )]
+//! pub struct Username(String);
+//!
+//! let errors: UsernameErrors = Username::try_new("a-b").unwrap_err();
+//! assert_eq!(errors.len(), 2);
+//! assert!(errors.contains(&UsernameError::LenCharMinViolated));
+//! assert!(errors.contains(&UsernameError::PredicateViolated));
+//!
+//! for error in &errors {
+//!     println!("{error}");
+//! }
+//! ```
+//!
+//! The violations are reported in the order the validators are declared.
+//! `report = all` cannot be combined with custom `with` validation or with `const_fn`.
+//! In `no_std` environment it requires `extern crate alloc;`.
+//!
+//! ## Detailed error variants
+//!
+//! By default the generated error variants carry no data, e.g. `AgeError::GreaterOrEqualViolated`.
+//! With `detailed_errors` flag the variants of bound validators also carry the violated bound and the actual value,
+//! so the errors can be turned into machine-readable responses without parsing the messages:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 18, less = 150, detailed_errors),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Age(u8);
+//!
+//! assert_eq!(
+//!     Age::try_new(17),
+//!     Err(AgeError::GreaterOrEqualViolated { min: 18, actual: 17 }),
+//! );
+//! ```
+//!
+//! The affected validators are:
+//!
+//! | Validators                                                           | Variant fields              |
+//! |----------------------------------------------------------------------|-----------------------------|
+//! | `len_char_min`, `len_utf16_min`, `len_bytes_min`, `len_grapheme_min` | `min: usize, actual: usize` |
+//! | `len_char_max`, `len_utf16_max`, `len_bytes_max`, `len_grapheme_max` | `max: usize, actual: usize` |
+//! | `greater`, `greater_or_equal`                                        | `min: T, actual: T`         |
+//! | `less`, `less_or_equal`                                              | `max: T, actual: T`         |
+//!
+//! Other validators (e.g. `not_empty`, `predicate`) keep unit variants.
+//! Note that with float inner types the detailed error type does not implement `Eq`.
+//!
+//!
+//! ## Error codes
+//!
+//! Every generated `*Error`, `*Errors` and `*ParseError` type implements `nutype::ValidationError` trait,
+//! which exposes a stable machine-readable description of the violation:
+//!
+//! ```
+//! use nutype::{nutype, ValidationError};
+//!
+//! #[nutype(validate(not_empty, len_char_max = 20), derive(Debug, FromStr))]
+//! pub struct Username(String);
+//!
+//! let err = Username::try_new("").unwrap_err();
+//! assert_eq!(err.code(), "not_empty");
+//! assert_eq!(err.type_name(), "Username");
+//!
+//! let err = "a".repeat(21).parse::<Username>().unwrap_err();
+//! assert_eq!(err.code(), "len_char_max");
+//! assert_eq!(err.rule(), "len_char_max = 20");
+//! ```
+//!
+//! The code is the name of the violated validator.
+//! `*ParseError` reports `"parse"` when the inner value cannot be parsed and `"with"` when a custom `with` validation fails.
+//!
+//!
+//! ## Generic code with `Nutype` trait
+//!
+//! Newtypes around strings, integers and floats implement `nutype::Nutype` trait,
+//! which allows to write generic code over any of them:
+//!
+//! ```
+//! use nutype::{nutype, Nutype};
+//!
+//! #[nutype(validate(greater = 0), derive(Debug))]
+//! pub struct UserId(i64);
+//!
+//! #[nutype(sanitize(trim), derive(Debug))]
+//! pub struct Comment(String);
+//!
+//! fn load<T: Nutype>(raw: T::Inner) -> Result<T, T::Error> {
+//!     T::try_new(raw)
+//! }
+//!
+//! let id: UserId = load(42).unwrap();
+//! assert_eq!(id.as_inner(), &42);
+//!
+//! let comment: Comment = load("  Hi!  ".to_string()).unwrap();
+//! assert_eq!(comment.into_inner(), "Hi!");
+//! ```
+//!
+//! For types without validation `Nutype::Error` is `core::convert::Infallible`.
+//! The trait is not implemented for types with non-public constructor or custom `with` validation.
+//!
+//!
+//! ## Constraints metadata
+//!
+//! Every generated type exposes its sanitizers and validators with `CONSTRAINTS` associated constant,
+//! so the rules can be rendered by frontends or documentation generators from a single source of truth:
+//!
+//! ```
+//! use nutype::{nutype, Constraint, ConstraintValue};
+//!
+//! #[nutype(sanitize(trim), validate(not_empty, len_char_max = 20))]
+//! pub struct Username(String);
+//!
+//! assert_eq!(
+//!     Username::CONSTRAINTS[2],
+//!     Constraint::Validator {
+//!         kind: "len_char_max",
+//!         value: Some(ConstraintValue::Length(20)),
+//!     },
+//! );
+//! ```
+//!
+//! Bounds defined with constants or statics (e.g. `len_char_max = MAX_LEN`) are evaluated at compile time.
+//! Bounds which cannot be evaluated in const context (e.g. function calls) and regex constants are exposed
+//! as `ConstraintValue::Expr` with the source code.
+//!
+//!
+//! ## Generated documentation
+//!
+//! The macro appends sections to the documentation of the type, which list the sanitizers (in the order they are applied),
+//! the validators with their bounds, the error type and the derived traits.
+//! `try_new()`, `new()` and the variants of the generated error type are documented as well,
+//! so the invariants of a type are visible in an IDE and on docs.rs.
+//! Traits derived within `cfg_attr(...)` are listed together with their predicate, e.g. `` `Serialize` with `cfg(feature = "serde")` ``.
+//!
+//!
+//! ## Validation without construction
+//!
+//! Besides the constructor, a type exposes its rules as associated functions, so a raw value
+//! can be checked without constructing the newtype (e.g. for live form validation):
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(not_empty, len_char_max = 20),
+//!     derive(Debug),
+//! )]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Username::validate(""), Err(UsernameError::NotEmptyViolated));
+//! assert!(Username::is_valid("alice"));
+//! assert_eq!(Username::sanitize("  alice ".to_string()), "alice");
+//! ```
+//!
+//! `validate()` and `is_valid()` do not sanitize the value. For string types they take `&str`, so no allocation is needed.
+//! Types without validation expose only `sanitize()`.
+//!
 //!
 //! ## Deriving Traits
 //!
@@ -634,6 +1119,8 @@
 //! This is useful when you want to restrict where instances can be created,
 //! ensuring they only come from trusted factory functions or specific modules.
 //!
+//! The visibility also applies to `sanitize()`, `validate()` and `is_valid()` functions.
+//!
 //! ## How to break the constraints?
 //!
 //! It's discouraged, but it's possible to bypass the constraints by enabling `new_unchecked` crate feature and marking a type with `new_unchecked`:
@@ -660,7 +1147,12 @@
 //! * `arbitrary` - enables derive of [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
 //! * `derive_unchecked` - enables `derive_unchecked` attribute to derive any arbitrary trait.
 //! * `new_unchecked` - enables generation of unsafe `::new_unchecked()` function.
-//! * `regex` - allows to use `regex = ` validation on string-based types. Note: your crate also has to explicitly have `regex` within its dependencies.
+//! * `regex` - allows to use `regex` and `not_regex` validation on string-based types. The [regex](https://crates.io/crates/regex) crate is re-exported as `nutype::regex`.
+//! * `regex_lite` - like `regex`, but uses the lightweight [regex-lite](https://crates.io/crates/regex-lite) crate, re-exported as `nutype::regex_lite`.
+//! * `regex_dfa` - allows `regex(pattern = "...", dfa)`, that compiles a regex literal to a DFA at build time. Implies `regex`.
+//! * `formats` - enables the format validators for string-based types: `email`, `url`, `uuid`, `ipv4`, `ipv6`, `hex`, `base64`, `semver` and `iso8601_date`.
+//! * `iso_codes` - enables the validators for string-based types backed by the embedded code tables: `iso3166_alpha2`, `iso3166_alpha3`, `iso4217` and `bcp47`.
+//! * `unicode` - enables `len_grapheme_min` and `len_grapheme_max` validators, that count grapheme clusters (user-perceived characters) of strings, and `nfc`, `nfd`, `nfkc`, `nfkd`, `casefold` and `slugify` sanitizers.
 //! * `serde` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits.
 //! * `schemars08` - allows to derive [`JsonSchema`](https://docs.rs/schemars/0.8.12/schemars/trait.JsonSchema.html) trait of [schemars](https://crates.io/crates/schemars) crate. Note that at the moment validation rules are not respected.
 //! * `std` - enabled by default. Use `default-features = false` to disable.
@@ -684,7 +1176,35 @@
 // Set `no_std` flag if `std` feature is disabled.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Allow the generated code to refer to `::nutype` within the crate's own tests.
+#[cfg(test)]
+extern crate self as nutype;
+
+mod constraint;
+mod nutype_trait;
+mod validation_error;
+
+#[doc(hidden)]
+pub mod __private;
+
+pub mod checksums;
+
+#[cfg(feature = "formats")]
+pub mod formats;
+
+#[cfg(feature = "iso_codes")]
+pub mod iso_codes;
+
+pub use constraint::{Constraint, ConstraintValue};
 pub use nutype_macros::nutype;
+pub use nutype_trait::Nutype;
+pub use validation_error::ValidationError;
+
+// Regex engines are re-exported, so the users do not need to depend on them explicitly.
+#[cfg(feature = "regex")]
+pub use regex;
+#[cfg(feature = "regex_lite")]
+pub use regex_lite;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,49 @@
+/// Common interface of the types generated by `#[nutype]` macro.
+///
+/// It allows to write generic code over any newtype, e.g. a repository layer that loads
+/// different newtypes from raw database values with a single function:
+///
+/// ```
+/// use nutype::{nutype, Nutype};
+///
+/// #[nutype(validate(greater = 0), derive(Debug))]
+/// pub struct UserId(i64);
+///
+/// #[nutype(sanitize(trim), derive(Debug))]
+/// pub struct Comment(String);
+///
+/// fn load<T: Nutype>(raw: T::Inner) -> Result<T, T::Error> {
+///     T::try_new(raw)
+/// }
+///
+/// let id: UserId = load(42).unwrap();
+/// assert_eq!(id.as_inner(), &42);
+/// assert!(load::<UserId>(-1).is_err());
+///
+/// // Types without validation never fail.
+/// let comment: Comment = load("  Hi!  ".to_string()).unwrap();
+/// assert_eq!(comment.into_inner(), "Hi!");
+/// ```
+///
+/// The trait is implemented for newtypes around strings, integers and floats. It's not implemented:
+/// * for other inner types, because the inner type may be private;
+/// * when the constructor is not public (see `constructor(visibility = ...)`),
+///   otherwise it would allow to bypass the restricted constructor;
+/// * when custom `with` validation is used, because the custom error type may be private.
+pub trait Nutype: Sized {
+    /// The wrapped inner type.
+    type Inner;
+
+    /// Error returned by [`Nutype::try_new`].
+    /// It's [`core::convert::Infallible`] for the types without validation.
+    type Error;
+
+    /// Sanitizes and validates the inner value and creates the newtype.
+    fn try_new(inner: Self::Inner) -> Result<Self, Self::Error>;
+
+    /// Consumes the newtype and returns the inner value.
+    fn into_inner(self) -> Self::Inner;
+
+    /// Returns a reference to the inner value.
+    fn as_inner(&self) -> &Self::Inner;
+}
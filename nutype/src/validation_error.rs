@@ -0,0 +1,33 @@
+/// Common interface of the validation errors generated by `#[nutype]` macro.
+///
+/// It's implemented by every generated `*Error` and `*ParseError` type (and by `*Errors`
+/// collection when `report = all` is used), so errors of different nutypes can be handled
+/// generically, e.g. mapped into HTTP responses.
+///
+/// ```
+/// use nutype::{nutype, ValidationError};
+///
+/// #[nutype(validate(len_char_max = 20), derive(Debug))]
+/// pub struct Username(String);
+///
+/// let err = Username::try_new("a".repeat(21)).unwrap_err();
+/// assert_eq!(err.code(), "len_char_max");
+/// assert_eq!(err.type_name(), "Username");
+/// assert_eq!(err.rule(), "len_char_max = 20");
+/// ```
+pub trait ValidationError: core::fmt::Debug + core::fmt::Display {
+    /// Stable machine-readable code of the violated rule.
+    /// It's the name of the validator, e.g. `"len_char_max"` or `"greater_or_equal"`.
+    ///
+    /// Special codes are:
+    /// * `"parse"` - a `*ParseError` failed to parse the inner value from a string
+    /// * `"with"` - a `*ParseError` wraps an error of a custom `with` validation
+    fn code(&self) -> &'static str;
+
+    /// Name of the nutype the error belongs to, e.g. `"Username"`.
+    fn type_name(&self) -> &'static str;
+
+    /// The violated rule as it's declared within `validate(...)` attribute,
+    /// e.g. `"len_char_max = 20"` or `"less_or_equal = MAX_AGE"`.
+    fn rule(&self) -> &'static str;
+}
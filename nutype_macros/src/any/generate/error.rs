@@ -4,7 +4,10 @@ use quote::quote;
 use crate::{
     any::models::AnyValidator,
     common::{
-        generate::error::gen_impl_error_trait,
+        generate::{
+            doc::gen_error_variant_doc,
+            error::{ViolatedRule, gen_impl_error_trait, gen_impl_validation_error_trait},
+        },
         models::{ErrorTypePath, TypeName},
     },
 };
@@ -17,6 +20,11 @@ pub fn gen_validation_error_type(
     let definition = gen_definition(error_type_path, validators);
     let impl_display_trait = gen_impl_display_trait(type_name, error_type_path, validators);
     let impl_error_trait = gen_impl_error_trait(error_type_path);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        error_type_path,
+        &gen_violated_rules(error_type_path, validators),
+    );
 
     quote! {
         #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,16 +32,21 @@ pub fn gen_validation_error_type(
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
 fn gen_definition(error_type_path: &ErrorTypePath, validators: &[AnyValidator]) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            AnyValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
+        .map(|validator| {
+            let doc = gen_error_variant_doc(&gen_validator_rule(validator));
+            let variant = match validator {
+                AnyValidator::Predicate(_) => {
+                    quote!(PredicateViolated,)
+                }
+            };
+            quote!(#doc #variant)
         })
         .collect();
 
@@ -66,3 +79,30 @@ fn gen_impl_display_trait(
         }
     }
 }
+
+fn gen_violated_rules(
+    error_type_path: &ErrorTypePath,
+    validators: &[AnyValidator],
+) -> Vec<ViolatedRule> {
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = match validator {
+                AnyValidator::Predicate(_) => quote!(#error_type_path::PredicateViolated),
+            };
+            ViolatedRule {
+                pattern,
+                code: validator.kind().to_string(),
+                rule: gen_validator_rule(validator),
+            }
+        })
+        .collect()
+}
+
+/// Describes the validator as it's declared, e.g. `predicate`.
+pub fn gen_validator_rule(validator: &AnyValidator) -> TokenStream {
+    let code = validator.kind().to_string();
+    match validator {
+        AnyValidator::Predicate(_) => quote!(#code),
+    }
+}
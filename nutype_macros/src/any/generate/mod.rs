@@ -9,11 +9,15 @@ use syn::{Generics, parse_quote};
 
 use crate::common::{
     generate::{
-        GenerateNewtype, tests::gen_test_should_have_valid_default_value, traits::GeneratedTraits,
+        GenerateNewtype,
+        constraints::gen_validator_constraint,
+        report::{gen_report_violation, gen_validation_result},
+        tests::gen_test_should_have_valid_default_value,
+        traits::GeneratedTraits,
     },
     models::{
-        ConditionalDeriveGroup, ConstFn, ErrorTypePath, Guard, SpannedDeriveUnsafeTrait, TypeName,
-        TypedCustomFunction,
+        ConditionalDeriveGroup, ConstFn, ErrorTypePath, ErrorVariants, Guard,
+        SpannedDeriveUnsafeTrait, TypeName, TypedCustomFunction, ValidationReport,
     },
 };
 
@@ -32,6 +36,9 @@ impl GenerateNewtype for AnyNewtype {
     type InnerType = AnyInnerType;
     type TypedTrait = AnyDeriveTrait;
 
+    // The inner type may be private, so it cannot be exposed as `Nutype::Inner` of a public trait.
+    const IMPLEMENTS_NUTYPE_TRAIT: bool = false;
+
     fn gen_fn_sanitize(
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
@@ -63,10 +70,31 @@ impl GenerateNewtype for AnyNewtype {
         )
     }
 
+    fn sanitizer_kind(sanitizer: &Self::Sanitizer) -> String {
+        match sanitizer {
+            AnySanitizer::With(_) => "with".to_string(),
+        }
+    }
+
+    fn gen_validator_rule(validator: &Self::Validator) -> TokenStream {
+        error::gen_validator_rule(validator)
+    }
+
+    fn gen_validator_constraint(
+        _inner_type: &Self::InnerType,
+        validator: &Self::Validator,
+    ) -> TokenStream {
+        match validator {
+            AnyValidator::Predicate(_) => gen_validator_constraint("predicate", None),
+        }
+    }
+
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        report: &ValidationReport,
+        _error_variants: ErrorVariants,
         const_fn: ConstFn,
     ) -> TokenStream {
         let validations: TokenStream = validators
@@ -80,15 +108,20 @@ impl GenerateNewtype for AnyNewtype {
                         .clone()
                         .try_into_typed(&inner_type_ref)
                         .expect("Failed to convert predicate into a typed closure");
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::PredicateViolated));
                     quote!(
                         if !(#typed_predicate)(val) {
-                            return Err(#error_type_path::PredicateViolated);
+                            #violation
                         }
                     )
                 }
             })
             .collect();
 
+        let validation_result = gen_validation_result(report, validations);
+        let returned_error_type_path = report.returned_error_type_path(error_type_path);
+
         quote!(
             // NOTE 1: we're using a unique lifetime name `nutype_a` in a hope that it will not clash
             // with any other lifetimes in the user's code.
@@ -103,17 +136,20 @@ impl GenerateNewtype for AnyNewtype {
             // Since this code is generic which is used for different inner types (not only Cow), we cannot easily fix it to make
             // clippy happy.
             #[allow(clippy::ptr_arg)]
-            #const_fn fn __validate__<'nutype_a>(val: &'nutype_a #inner_type) -> ::core::result::Result<(), #error_type_path> {
-                #validations
-                Ok(())
+            #const_fn fn __validate__<'nutype_a>(val: &'nutype_a #inner_type) -> ::core::result::Result<(), #returned_error_type_path> {
+                #validation_result
             }
         )
     }
 
+    // Predicate is the only validator available for `any` types, so there are no bounds to carry
+    // in the detailed error variants.
     fn gen_validation_error_type(
         type_name: &TypeName,
+        _inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        _error_variants: ErrorVariants,
     ) -> TokenStream {
         gen_validation_error_type(type_name, error_type_path, validators)
     }
@@ -0,0 +1,79 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::Generics;
+
+use crate::common::{
+    generate::generics::SplitGenerics,
+    models::{TypeName, ValueOrExpr},
+};
+
+/// Generates `CONSTRAINTS` associated constant, which exposes the sanitizers and validators
+/// of the type at runtime.
+pub fn gen_impl_constraints(
+    type_name: &TypeName,
+    generics: &Generics,
+    constraints: &[TokenStream],
+) -> TokenStream {
+    let SplitGenerics {
+        impl_generics,
+        type_generics,
+        where_clause,
+    } = SplitGenerics::new(generics);
+
+    quote! {
+        impl #impl_generics #type_name #type_generics #where_clause {
+            /// Sanitizers and validators of the type in the order of declaration.
+            #[allow(clippy::unnecessary_cast, reason = "bounds are cast to the widest type of the same kind")]
+            pub const CONSTRAINTS: &'static [::nutype::Constraint] = &[
+                #(#constraints),*
+            ];
+        }
+    }
+}
+
+pub fn gen_sanitizer_constraint(kind: &str) -> TokenStream {
+    quote!(::nutype::Constraint::Sanitizer { kind: #kind })
+}
+
+pub fn gen_validator_constraint(kind: &str, maybe_value: Option<TokenStream>) -> TokenStream {
+    let value = match maybe_value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None),
+    };
+    quote!(::nutype::Constraint::Validator { kind: #kind, value: #value })
+}
+
+/// Generates `ConstraintValue` of a bound, e.g. `ConstraintValue::Unsigned(MAX as u128)`.
+/// If the expression of the bound cannot be evaluated in const context,
+/// it's exposed as `ConstraintValue::Expr` with the source code.
+pub fn gen_bound_constraint_value<T: ToTokens>(
+    bound: &ValueOrExpr<T>,
+    variant: TokenStream,
+    target_type: TokenStream,
+) -> TokenStream {
+    match bound {
+        ValueOrExpr::Expr(expr) if !is_const_evaluable(expr) => gen_expr_constraint_value(expr),
+        _ => quote!(::nutype::ConstraintValue::#variant((#bound) as #target_type)),
+    }
+}
+
+pub fn gen_expr_constraint_value(expr: impl ToTokens) -> TokenStream {
+    quote!(::nutype::ConstraintValue::Expr(::core::stringify!(#expr)))
+}
+
+/// Conservative check: constants, literals and operations on them.
+/// A path may refer to a `static` too, which can be read in const context, unless it's mutable.
+/// Function calls are not considered evaluable, since we cannot know whether they are `const fn`.
+fn is_const_evaluable(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(_) | syn::Expr::Path(_) => true,
+        syn::Expr::Unary(unary) => is_const_evaluable(&unary.expr),
+        syn::Expr::Binary(binary) => {
+            is_const_evaluable(&binary.left) && is_const_evaluable(&binary.right)
+        }
+        syn::Expr::Paren(paren) => is_const_evaluable(&paren.expr),
+        syn::Expr::Group(group) => is_const_evaluable(&group.expr),
+        syn::Expr::Cast(cast) => is_const_evaluable(&cast.expr),
+        _ => false,
+    }
+}
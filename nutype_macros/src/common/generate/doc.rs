@@ -0,0 +1,444 @@
+//! Generation of documentation, that describes sanitizers, validators and derived traits of a type.
+//! Rules of validators are given as expressions of `&'static str` (e.g. `concat!("len_char_max = ", stringify!(MAX))`),
+//! so every line is generated with `#[doc = concat!(...)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::common::models::{DerivedTraitNames, ErrorTypePath, Validation, ValidationReport};
+
+/// Generates documentation appended to the user's documentation of the type.
+pub fn gen_type_doc<Validator>(
+    has_user_doc: bool,
+    sanitizer_kinds: &[String],
+    validator_rules: &[TokenStream],
+    maybe_validation: Option<&Validation<Validator>>,
+    derived_trait_names: &DerivedTraitNames,
+) -> TokenStream {
+    let mut sections: Vec<TokenStream> = Vec::new();
+
+    if !sanitizer_kinds.is_empty() {
+        let lines = gen_sanitizer_lines(sanitizer_kinds);
+        sections.push(quote! {
+            #[doc = " # Sanitizers"]
+            #lines
+        });
+    }
+
+    if let Some(validation) = maybe_validation {
+        let lines = gen_validator_lines(validator_rules);
+        let error_line = gen_doc_line(gen_error_description(validation));
+        sections.push(quote! {
+            #[doc = " # Validators"]
+            #lines
+            #[doc = ""]
+            #error_line
+        });
+    }
+
+    let DerivedTraitNames {
+        unconditional,
+        conditional,
+    } = derived_trait_names;
+    if !unconditional.is_empty() || !conditional.is_empty() {
+        let mut lines: Vec<String> = Vec::new();
+        if !unconditional.is_empty() {
+            lines.push(format!(" {}", format_trait_names(unconditional)));
+        }
+        // The predicates are not evaluated by the macro, so they are listed as they are.
+        for (predicate, names) in conditional {
+            lines.push(format!(
+                " {} with `cfg({predicate})`",
+                format_trait_names(names)
+            ));
+        }
+        // Separate the lines, so rustdoc renders them as paragraphs.
+        let lines = lines.iter().enumerate().map(|(index, line)| {
+            if index > 0 {
+                quote!(#[doc = ""] #[doc = #line])
+            } else {
+                quote!(#[doc = #line])
+            }
+        });
+        sections.push(quote! {
+            #[doc = " # Derived traits"]
+            #(#lines)*
+        });
+    }
+
+    let mut output = TokenStream::new();
+    for (index, section) in sections.into_iter().enumerate() {
+        if index > 0 || has_user_doc {
+            output.extend(quote!(#[doc = ""]));
+        }
+        output.extend(section);
+    }
+    output
+}
+
+/// Generates documentation of `new()` constructor.
+pub fn gen_new_doc(sanitizer_kinds: &[String]) -> TokenStream {
+    if sanitizer_kinds.is_empty() {
+        return quote!(#[doc = " Creates a new value."]);
+    }
+    let lines = gen_sanitizer_lines(sanitizer_kinds);
+    quote! {
+        #[doc = " Creates a new value, applying the sanitizers:"]
+        #lines
+    }
+}
+
+/// Generates documentation of `try_new()` constructor.
+pub fn gen_try_new_doc<Validator>(
+    sanitizer_kinds: &[String],
+    validator_rules: &[TokenStream],
+    validation: &Validation<Validator>,
+) -> TokenStream {
+    let maybe_sanitizers = if sanitizer_kinds.is_empty() {
+        quote!()
+    } else {
+        let lines = gen_sanitizer_lines(sanitizer_kinds);
+        quote! {
+            #[doc = ""]
+            #[doc = " The value is sanitized before validation:"]
+            #lines
+        }
+    };
+    let lines = gen_validator_lines(validator_rules);
+    let error_line = gen_doc_line(gen_error_description(validation));
+
+    quote! {
+        #[doc = " Creates a new value, if it passes the validation."]
+        #maybe_sanitizers
+        #[doc = ""]
+        #[doc = " # Errors"]
+        #error_line
+        #[doc = ""]
+        #[doc = " The validators are:"]
+        #lines
+    }
+}
+
+/// Generates documentation of an error variant.
+pub fn gen_error_variant_doc(rule: &TokenStream) -> TokenStream {
+    quote!(#[doc = ::core::concat!(" Violation of `", #rule, "`.")])
+}
+
+fn format_trait_names(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn gen_sanitizer_lines(sanitizer_kinds: &[String]) -> TokenStream {
+    sanitizer_kinds
+        .iter()
+        .enumerate()
+        .map(|(index, kind)| {
+            let line = format!(" {}. `{kind}`", index + 1);
+            quote!(#[doc = #line])
+        })
+        .collect()
+}
+
+fn gen_validator_lines(validator_rules: &[TokenStream]) -> TokenStream {
+    validator_rules
+        .iter()
+        .map(|rule| quote!(#[doc = ::core::concat!(" * `", #rule, "`")]))
+        .collect()
+}
+
+fn gen_error_description<Validator>(validation: &Validation<Validator>) -> TokenStream {
+    match validation {
+        Validation::Standard {
+            error_type_path,
+            report,
+            ..
+        } => match report {
+            ValidationReport::First => {
+                let line = gen_error_type_link(error_type_path);
+                quote!(::core::concat!(" Violations are reported with ", #line, "."))
+            }
+            ValidationReport::All { errors_type_path } => {
+                let line = gen_error_type_link(errors_type_path);
+                quote!(::core::concat!(" All the violations are collected into ", #line, "."))
+            }
+        },
+        Validation::Custom {
+            error_type_path, ..
+        } => quote!(::core::concat!(
+            " Violations are reported with `",
+            ::core::stringify!(#error_type_path),
+            "`."
+        )),
+    }
+}
+
+// The generated error types are defined next to the type, so they can be linked.
+fn gen_error_type_link(error_type_path: &ErrorTypePath) -> TokenStream {
+    let link = format!("[`{}`]", quote!(#error_type_path));
+    quote!(#link)
+}
+
+fn gen_doc_line(line: TokenStream) -> TokenStream {
+    quote!(#[doc = #line])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_doc_without_rules_is_empty() {
+        let doc = gen_type_doc::<()>(true, &[], &[], None, &DerivedTraitNames::default());
+        assert!(doc.is_empty());
+    }
+
+    #[test]
+    fn test_type_doc_sections() {
+        let doc = gen_type_doc::<()>(
+            false,
+            &["trim".to_string()],
+            &[],
+            None,
+            &DerivedTraitNames {
+                unconditional: vec!["Debug".to_string(), "Clone".to_string()],
+                conditional: vec![(
+                    "feature = \"serde\"".to_string(),
+                    vec!["Serialize".to_string()],
+                )],
+            },
+        );
+        let expected = quote! {
+            #[doc = " # Sanitizers"]
+            #[doc = " 1. `trim`"]
+            #[doc = ""]
+            #[doc = " # Derived traits"]
+            #[doc = " `Debug`, `Clone`"]
+            #[doc = ""]
+            #[doc = " `Serialize` with `cfg(feature = \"serde\")`"]
+        };
+        assert_eq!(doc.to_string(), expected.to_string());
+    }
+
+    mod expansion {
+        use proc_macro2::TokenStream;
+        use quote::quote;
+
+        /// Documentation of the generated items, as rendered by rustdoc.
+        struct Docs {
+            type_doc: String,
+            try_new_doc: Option<String>,
+            error_variant_docs: Vec<String>,
+        }
+
+        /// Expands `#[nutype]` and renders `#[doc]` attributes of the generated type,
+        /// its `try_new()` and the variants of the generated error.
+        fn expand_docs(attrs: TokenStream, type_definition: TokenStream) -> Docs {
+            let type_name = syn::parse2::<syn::DeriveInput>(type_definition.clone())
+                .expect("type definition")
+                .ident;
+            let output = crate::expand_nutype(attrs, type_definition).expect("expansion failed");
+            let file: syn::File = syn::parse2(output).expect("invalid expansion");
+            let items = file
+                .items
+                .iter()
+                .find_map(|item| match item {
+                    syn::Item::Mod(module) => module.content.as_ref().map(|(_, items)| items),
+                    _ => None,
+                })
+                .expect("module of the type");
+
+            let mut docs = Docs {
+                type_doc: String::new(),
+                try_new_doc: None,
+                error_variant_docs: Vec::new(),
+            };
+            for item in items {
+                match item {
+                    syn::Item::Struct(item) if item.ident == type_name => {
+                        docs.type_doc = render_doc(&item.attrs)
+                    }
+                    syn::Item::Impl(item) => {
+                        for impl_item in &item.items {
+                            if let syn::ImplItem::Fn(f) = impl_item
+                                && f.sig.ident == "try_new"
+                                && item.trait_.is_none()
+                            {
+                                docs.try_new_doc = Some(render_doc(&f.attrs));
+                            }
+                        }
+                    }
+                    syn::Item::Enum(item) if item.ident.to_string().ends_with("Error") => {
+                        docs.error_variant_docs =
+                            item.variants.iter().map(|v| render_doc(&v.attrs)).collect();
+                    }
+                    _ => {}
+                }
+            }
+            docs
+        }
+
+        fn render_doc(attrs: &[syn::Attribute]) -> String {
+            attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"))
+                .map(|attr| match &attr.meta {
+                    syn::Meta::NameValue(name_value) => render_expr(&name_value.value),
+                    _ => panic!("unexpected doc attribute"),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+
+        // Evaluates `concat!` and `stringify!` the way the compiler does for the doc attributes.
+        fn render_expr(expr: &syn::Expr) -> String {
+            match expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) => lit.value(),
+                syn::Expr::Lit(lit) => quote!(#lit).to_string(),
+                syn::Expr::Macro(expr_macro) => {
+                    let name = &expr_macro.mac.path.segments.last().unwrap().ident;
+                    if name == "stringify" {
+                        expr_macro.mac.tokens.to_string()
+                    } else if name == "concat" {
+                        let args = expr_macro
+                            .mac
+                            .parse_body_with(
+                                syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated,
+                            )
+                            .unwrap();
+                        args.iter().map(render_expr).collect()
+                    } else {
+                        panic!("unexpected macro `{name}` in doc attribute")
+                    }
+                }
+                _ => panic!("unexpected doc expression: {}", quote!(#expr)),
+            }
+        }
+
+        #[test]
+        fn test_string_type() {
+            let docs = expand_docs(
+                quote!(
+                    sanitize(trim, lowercase),
+                    validate(not_empty, len_char_max = MAX_LEN),
+                    derive(Debug, Clone),
+                    cfg_attr(test, derive(PartialEq, Eq)),
+                ),
+                quote!(
+                    /// A user name.
+                    pub struct Username(String);
+                ),
+            );
+            let expected_type_doc = [
+                " A user name.",
+                "",
+                " # Sanitizers",
+                " 1. `trim`",
+                " 2. `lowercase`",
+                "",
+                " # Validators",
+                " * `not_empty`",
+                " * `len_char_max = MAX_LEN`",
+                "",
+                " Violations are reported with [`UsernameError`].",
+                "",
+                " # Derived traits",
+                " `Debug`, `Clone`",
+                "",
+                " `PartialEq`, `Eq` with `cfg(test)`",
+            ];
+            assert_eq!(docs.type_doc, expected_type_doc.join("\n"));
+
+            let expected_try_new_doc = [
+                " Creates a new value, if it passes the validation.",
+                "",
+                " The value is sanitized before validation:",
+                " 1. `trim`",
+                " 2. `lowercase`",
+                "",
+                " # Errors",
+                " Violations are reported with [`UsernameError`].",
+                "",
+                " The validators are:",
+                " * `not_empty`",
+                " * `len_char_max = MAX_LEN`",
+            ];
+            assert_eq!(docs.try_new_doc.unwrap(), expected_try_new_doc.join("\n"));
+
+            assert_eq!(
+                docs.error_variant_docs,
+                [
+                    " Violation of `not_empty`.",
+                    " Violation of `len_char_max = MAX_LEN`.",
+                ]
+            );
+        }
+
+        #[test]
+        fn test_integer_type_with_report_all() {
+            let docs = expand_docs(
+                quote!(validate(greater = 0, less = 10, report = all)),
+                quote!(
+                    pub struct Digit(u8);
+                ),
+            );
+            let expected_type_doc = [
+                " # Validators",
+                " * `greater = 0`",
+                " * `less = 10`",
+                "",
+                " All the violations are collected into [`DigitErrors`].",
+            ];
+            assert_eq!(docs.type_doc, expected_type_doc.join("\n"));
+            assert_eq!(
+                docs.error_variant_docs,
+                [" Violation of `greater = 0`.", " Violation of `less = 10`."]
+            );
+        }
+
+        #[test]
+        fn test_custom_validation() {
+            let docs = expand_docs(
+                quote!(
+                    validate(with = is_even, error = EvenError),
+                    derive(Debug),
+                    cfg_attr(feature = "std", derive(Hash)),
+                ),
+                quote!(
+                    pub struct Even(i64);
+                ),
+            );
+            let expected_type_doc = [
+                " # Validators",
+                " * `with = is_even`",
+                "",
+                " Violations are reported with `EvenError`.",
+                "",
+                " # Derived traits",
+                " `Debug`",
+                "",
+                " `Hash` with `cfg(feature = \"std\")`",
+            ];
+            assert_eq!(docs.type_doc, expected_type_doc.join("\n"));
+            assert!(docs.error_variant_docs.is_empty());
+        }
+
+        #[test]
+        fn test_type_without_rules() {
+            let docs = expand_docs(
+                quote!(),
+                quote!(
+                    pub struct Note(String);
+                ),
+            );
+            assert_eq!(docs.type_doc, "");
+            assert!(docs.try_new_doc.is_none());
+        }
+    }
+}
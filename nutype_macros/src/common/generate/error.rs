@@ -1,8 +1,10 @@
+use core::fmt::Debug;
+
 use cfg_if::cfg_if;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote};
 
-use crate::common::models::{ErrorTypePath, TypeName};
+use crate::common::models::{ErrorTypePath, ErrorVariants, TypeName, ValueOrExpr};
 
 /// Generate a default error type name if the error name is not specified explicitly by
 /// the user in the attributes.
@@ -11,6 +13,200 @@ pub fn gen_error_type_name(type_name: &TypeName) -> ErrorTypePath {
     ErrorTypePath::new(ident)
 }
 
+/// Describes how a single error variant is exposed through `::nutype::ValidationError` trait.
+pub struct ViolatedRule {
+    /// Pattern that matches the error variant, e.g. `UsernameError::LenCharMaxViolated { .. }`
+    pub pattern: TokenStream,
+
+    /// Stable code of the rule. It's the name of the validator, e.g. `len_char_max`
+    pub code: String,
+
+    /// Expression of `&'static str` type that represents the rule, e.g. `"len_char_max = 20"`
+    pub rule: TokenStream,
+}
+
+/// Generate a rule representation for a validator with a value, e.g. `len_char_max = 20`.
+/// If the value is an expression (e.g. a constant), it's stringified as it is.
+pub fn gen_rule<T: Debug>(code: &str, value: &ValueOrExpr<T>) -> TokenStream {
+    match value {
+        ValueOrExpr::Value(value) => {
+            let rule = format!("{code} = {value:?}");
+            quote!(#rule)
+        }
+        ValueOrExpr::Expr(expr) => gen_rule_from_tokens(code, expr),
+    }
+}
+
+/// Generate a rule representation where the value is given as tokens, e.g. `regex = EMAIL_REGEX`.
+pub fn gen_rule_from_tokens(code: &str, value: impl ToTokens) -> TokenStream {
+    let prefix = format!("{code} = ");
+    quote!(::core::concat!(#prefix, ::core::stringify!(#value)))
+}
+
+pub fn gen_impl_validation_error_trait(
+    type_name: &TypeName,
+    error_type_path: &ErrorTypePath,
+    rules: &[ViolatedRule],
+) -> TokenStream {
+    let code_arms = rules
+        .iter()
+        .map(|ViolatedRule { pattern, code, .. }| quote!(#pattern => #code));
+    let rule_arms = rules
+        .iter()
+        .map(|ViolatedRule { pattern, rule, .. }| quote!(#pattern => #rule));
+
+    quote! {
+        impl ::nutype::ValidationError for #error_type_path {
+            fn code(&self) -> &'static str {
+                match self {
+                    #(#code_arms,)*
+                }
+            }
+
+            fn type_name(&self) -> &'static str {
+                ::core::stringify!(#type_name)
+            }
+
+            fn rule(&self) -> &'static str {
+                match self {
+                    #(#rule_arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generate an error variant: either a unit one or a struct-like one with the given fields
+/// (when `detailed_errors` is set).
+/// It is used for both: definition of variants and their instantiation.
+pub fn gen_error_variant(
+    error_variants: ErrorVariants,
+    variant: TokenStream,
+    fields: TokenStream,
+) -> TokenStream {
+    match error_variants {
+        ErrorVariants::Unit => variant,
+        ErrorVariants::Detailed => quote!(#variant { #fields }),
+    }
+}
+
+/// Generate a name for the collection of errors, which is returned by `try_new()`
+/// when validation is set up with `report = all`.
+pub fn gen_errors_type_name(type_name: &TypeName) -> ErrorTypePath {
+    let ident = format_ident!("{type_name}Errors");
+    ErrorTypePath::new(ident)
+}
+
+/// Generate a type that holds all the violated validation rules.
+/// It is used only with `report = all`.
+pub fn gen_validation_errors_type(
+    type_name: &TypeName,
+    error_type_path: &ErrorTypePath,
+    errors_type_path: &ErrorTypePath,
+    is_error_eq: bool,
+) -> TokenStream {
+    let vec = gen_vec_path();
+    let impl_error_trait = gen_impl_error_trait(errors_type_path);
+    let maybe_eq = if is_error_eq { quote!(, Eq) } else { quote!() };
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq #maybe_eq)]
+        pub struct #errors_type_path(#vec<#error_type_path>);
+
+        impl #errors_type_path {
+            /// Returns an iterator over the violated validation rules.
+            pub fn iter(&self) -> ::core::slice::Iter<'_, #error_type_path> {
+                self.0.iter()
+            }
+
+            /// Returns `true` if the given rule is among the violated ones.
+            pub fn contains(&self, error: &#error_type_path) -> bool {
+                self.0.contains(error)
+            }
+
+            /// Returns the number of violated validation rules.
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Returns `true` if there are no violations.
+            /// Errors returned by `try_new()` always contain at least one violation.
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            /// Returns the violated validation rules as a slice.
+            pub fn as_slice(&self) -> &[#error_type_path] {
+                &self.0
+            }
+
+            /// Converts the collection into a vector of the violated validation rules.
+            pub fn into_vec(self) -> #vec<#error_type_path> {
+                self.0
+            }
+        }
+
+        impl ::core::iter::IntoIterator for #errors_type_path {
+            type Item = #error_type_path;
+            type IntoIter = <#vec<#error_type_path> as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+
+        impl<'nutype_a> ::core::iter::IntoIterator for &'nutype_a #errors_type_path {
+            type Item = &'nutype_a #error_type_path;
+            type IntoIter = ::core::slice::Iter<'nutype_a, #error_type_path>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+
+        impl ::core::fmt::Display for #errors_type_path {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                for (index, error) in self.0.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" ")?;
+                    }
+                    ::core::fmt::Display::fmt(error, f)?;
+                }
+                Ok(())
+            }
+        }
+
+        #impl_error_trait
+
+        // The collection is never empty, so it exposes the first violation.
+        impl ::nutype::ValidationError for #errors_type_path {
+            fn code(&self) -> &'static str {
+                self.0.first().map_or("", ::nutype::ValidationError::code)
+            }
+
+            fn type_name(&self) -> &'static str {
+                ::core::stringify!(#type_name)
+            }
+
+            fn rule(&self) -> &'static str {
+                self.0.first().map_or("", ::nutype::ValidationError::rule)
+            }
+        }
+    }
+}
+
+/// Path to `Vec`, that works for both `std` and `no_std` environments.
+/// For `no_std` the user's crate is expected to declare `extern crate alloc;`.
+pub fn gen_vec_path() -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            quote!(::std::vec::Vec)
+        } else {
+            quote!(::alloc::vec::Vec)
+        }
+    }
+}
+
 // NOTE: `::core::error::Error` is stable only for rust >= 1.81.0.
 #[allow(unused_variables)]
 pub fn gen_impl_error_trait(error_type_path: &ErrorTypePath) -> TokenStream {
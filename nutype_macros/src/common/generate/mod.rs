@@ -1,7 +1,10 @@
+pub mod constraints;
+pub mod doc;
 pub mod error;
 pub mod generics;
 pub mod new_unchecked;
 pub mod parse_error;
+pub mod report;
 pub mod tests;
 pub mod traits;
 
@@ -12,12 +15,18 @@ use self::traits::GeneratedTraits;
 
 use super::models::{
     ConditionalDeriveGroup, ConstFn, ConstructorVisibility, CustomFunction, ErrorTypePath,
-    GenerateParams, Guard, NewUnchecked, ParseErrorTypeName, SpannedDeriveUnsafeTrait, TypeName,
-    TypeTrait,
+    ErrorVariants, GenerateParams, Guard, NewUnchecked, ParseErrorTypeName,
+    SpannedDeriveUnsafeTrait, TypeName, TypeTrait,
 };
 use crate::common::{
-    generate::{new_unchecked::gen_new_unchecked, parse_error::gen_parse_error_name},
-    models::{ModuleName, Validation},
+    generate::{
+        constraints::{gen_impl_constraints, gen_sanitizer_constraint, gen_validator_constraint},
+        doc::{gen_new_doc, gen_try_new_doc, gen_type_doc},
+        error::{gen_rule_from_tokens, gen_validation_errors_type},
+        new_unchecked::gen_new_unchecked,
+        parse_error::gen_parse_error_name,
+    },
+    models::{ModuleName, Validation, ValidationReport},
 };
 use proc_macro2::{Punct, Spacing, TokenStream, TokenTree};
 use quote::{ToTokens, format_ident, quote};
@@ -98,11 +107,34 @@ pub fn gen_module_name_for_type(type_name: &TypeName) -> ModuleName {
     ModuleName::new(ident)
 }
 
+/// Translates the visibility of the type into the visibility of an item within the module,
+/// that wraps the type, e.g. a private type becomes `pub(super)`.
+pub fn gen_visibility_within_module(vis: &Visibility) -> TokenStream {
+    match vis {
+        Visibility::Public(_) => quote!(pub),
+        Visibility::Inherited => quote!(pub(super)),
+        Visibility::Restricted(restricted) => {
+            // The path starts with `crate`, `self` or `super`, e.g. `pub(in super::foo)`.
+            let mut path = (*restricted.path).clone();
+            match path.segments.first_mut() {
+                Some(first) if first.ident == "self" => {
+                    first.ident = format_ident!("super");
+                }
+                Some(first) if first.ident == "super" => {
+                    path.segments.insert(0, format_ident!("super").into());
+                }
+                _ => {}
+            }
+            quote!(pub(in #path))
+        }
+    }
+}
+
 pub fn gen_reimports(
     vis: Visibility,
     type_name: &TypeName,
     module_name: &ModuleName,
-    maybe_error_type_path: Option<&ErrorTypePath>,
+    reimported_error_type_paths: &[&ErrorTypePath],
     maybe_parse_error_type_name: Option<&ParseErrorTypeName>,
     conditional_parse_error_reimports: &[(TokenStream, ParseErrorTypeName)],
 ) -> TokenStream {
@@ -110,14 +142,14 @@ pub fn gen_reimports(
         #vis use #module_name::#type_name;
     };
 
-    let reimport_error_type_if_needed = match maybe_error_type_path {
-        None => quote!(),
-        Some(ref error_type_path) => {
+    let reimport_error_types: TokenStream = reimported_error_type_paths
+        .iter()
+        .map(|error_type_path| {
             quote! (
                 #vis use #module_name::#error_type_path;
             )
-        }
-    };
+        })
+        .collect();
 
     let reimport_parse_error_type_if_needed = match maybe_parse_error_type_name {
         None => quote!(),
@@ -140,7 +172,7 @@ pub fn gen_reimports(
 
     quote! {
         #reimport_main_type
-        #reimport_error_type_if_needed
+        #reimport_error_types
         #reimport_parse_error_type_if_needed
         #reimport_conditional_parse_errors
     }
@@ -176,6 +208,114 @@ pub fn gen_impl_into_inner(
     }
 }
 
+/// Generates public `sanitize()` function, that exposes the sanitizers without constructing the type.
+fn gen_fn_sanitize_public(
+    inner_type: impl ToTokens,
+    const_fn: ConstFn,
+    constructor_visibility: &ConstructorVisibility,
+) -> TokenStream {
+    quote! {
+        /// Applies the sanitizers to the value.
+        #constructor_visibility #const_fn fn sanitize(value: #inner_type) -> #inner_type {
+            Self::__sanitize__(value)
+        }
+    }
+}
+
+/// Generates public `validate()` and `is_valid()` functions, that expose the validation without
+/// constructing the type.
+fn gen_fns_validate_public<Validator>(
+    validate_param_type: &TokenStream,
+    validation: &Validation<Validator>,
+    const_fn: ConstFn,
+    constructor_visibility: &ConstructorVisibility,
+) -> TokenStream {
+    let error_type_path = validation.error_type_path();
+
+    // Dropping of the error is not possible in const context, if the error type implements `Drop`.
+    // This is never the case for the generated error types, but custom errors can do it.
+    let is_valid_const_fn = match validation {
+        Validation::Standard { .. } => const_fn,
+        Validation::Custom { .. } => ConstFn::NoConst,
+    };
+
+    // The argument is a reference to the inner type (e.g. `&Cow<'a, str>` or `&String`), so
+    // clippy complains about `ptr_arg`, but it's inevitable for generic code.
+    quote! {
+        /// Checks the value against the validators. The value is not sanitized.
+        #[allow(clippy::ptr_arg)]
+        #constructor_visibility #const_fn fn validate(value: #validate_param_type) -> ::core::result::Result<(), #error_type_path> {
+            Self::__validate__(value)
+        }
+
+        /// Returns `true` if the value passes the validators. The value is not sanitized.
+        #[allow(clippy::ptr_arg)]
+        #constructor_visibility #is_valid_const_fn fn is_valid(value: #validate_param_type) -> bool {
+            match Self::__validate__(value) {
+                Ok(()) => true,
+                Err(_) => false,
+            }
+        }
+    }
+}
+
+/// Implements `::nutype::Nutype` trait, so the type can be used in generic code.
+/// The trait is not implemented:
+/// * for types with restricted constructor, because `try_new()` of the trait would bypass the visibility;
+/// * for types with custom `with` validation, because the user defined error type may be private
+///   and cannot be exposed as the associated type of a public trait.
+pub fn gen_impl_nutype_trait<Sanitizer, Validator>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    guard: &Guard<Sanitizer, Validator>,
+    constructor_visibility: &ConstructorVisibility,
+) -> TokenStream {
+    if !matches!(constructor_visibility, ConstructorVisibility::Public) {
+        return quote!();
+    }
+
+    let generics::SplitGenerics {
+        impl_generics,
+        type_generics,
+        where_clause,
+    } = generics::SplitGenerics::new(generics);
+
+    let (error_type, try_new_body) = match guard.maybe_validation() {
+        Some(Validation::Custom { .. }) => return quote!(),
+        Some(validation) => {
+            let error_type_path = validation.error_type_path();
+            (quote!(#error_type_path), quote!(Self::try_new(inner)))
+        }
+        None => (
+            quote!(::core::convert::Infallible),
+            quote!(Ok(Self::new(inner))),
+        ),
+    };
+
+    quote! {
+        impl #impl_generics ::nutype::Nutype for #type_name #type_generics #where_clause {
+            type Inner = #inner_type;
+            type Error = #error_type;
+
+            #[inline]
+            fn try_new(inner: Self::Inner) -> ::core::result::Result<Self, Self::Error> {
+                #try_new_body
+            }
+
+            #[inline]
+            fn into_inner(self) -> Self::Inner {
+                self.0
+            }
+
+            #[inline]
+            fn as_inner(&self) -> &Self::Inner {
+                &self.0
+            }
+        }
+    }
+}
+
 pub trait GenerateNewtype {
     type Sanitizer;
     type Validator;
@@ -195,25 +335,122 @@ pub trait GenerateNewtype {
     /// If it's true, then `::new()` function receives `impl Into<T>` instead of `T`.
     const NEW_CONVERT_INTO_INNER_TYPE: bool = false;
 
+    /// If `::nutype::Nutype` trait is implemented for the type.
+    const IMPLEMENTS_NUTYPE_TRAIT: bool = true;
+
     fn gen_fn_sanitize(
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
         const_fn: ConstFn,
     ) -> TokenStream;
 
+    /// Name of the sanitizer as it's declared, e.g. `trim`.
+    fn sanitizer_kind(sanitizer: &Self::Sanitizer) -> String;
+
+    /// Describes the validator as it's declared, e.g. `len_char_max = 20`.
+    /// Generates an expression of `&'static str` type, so it can be used within `concat!`.
+    fn gen_validator_rule(validator: &Self::Validator) -> TokenStream;
+
+    fn gen_validator_rules(validation: &Validation<Self::Validator>) -> Vec<TokenStream> {
+        match validation {
+            Validation::Standard { validators, .. } => {
+                validators.iter().map(Self::gen_validator_rule).collect()
+            }
+            Validation::Custom { with, .. } => vec![gen_rule_from_tokens("with", with)],
+        }
+    }
+
+    fn sanitizer_kinds(sanitizers: &[Self::Sanitizer]) -> Vec<String> {
+        sanitizers.iter().map(Self::sanitizer_kind).collect()
+    }
+
+    /// Describes the validator as `::nutype::Constraint` expression.
+    fn gen_validator_constraint(
+        inner_type: &Self::InnerType,
+        validator: &Self::Validator,
+    ) -> TokenStream;
+
+    fn gen_constraints(
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> Vec<TokenStream> {
+        let sanitizers = match guard {
+            Guard::WithoutValidation { sanitizers } | Guard::WithValidation { sanitizers, .. } => {
+                sanitizers
+            }
+        };
+        let sanitizer_constraints = sanitizers
+            .iter()
+            .map(|sanitizer| gen_sanitizer_constraint(&Self::sanitizer_kind(sanitizer)));
+        let validator_constraints: Vec<TokenStream> = match guard.maybe_validation() {
+            None => vec![],
+            Some(Validation::Standard { validators, .. }) => validators
+                .iter()
+                .map(|validator| Self::gen_validator_constraint(inner_type, validator))
+                .collect(),
+            Some(Validation::Custom { .. }) => vec![gen_validator_constraint("with", None)],
+        };
+        sanitizer_constraints.chain(validator_constraints).collect()
+    }
+
+    /// Generates constructors, that are specific to the inner type, e.g. `try_from_str()` for strings.
+    fn gen_extra_constructors(
+        _type_name: &TypeName,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+        _constructor_visibility: &ConstructorVisibility,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generates associated constants, that are specific to the inner type, e.g. the allowed
+    /// values of `one_of` validator for strings.
+    fn gen_extra_consts(
+        _type_name: &TypeName,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generates methods, that are specific to the inner type, e.g. the accessors of the named
+    /// capture groups of `regex` for strings. The methods get the visibility of the type.
+    fn gen_extra_methods(
+        _type_name: &TypeName,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+        _vis: &TokenStream,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Type of the argument of the generated `validate()` and `is_valid()` functions.
+    fn gen_validate_param_type(
+        inner_type: &Self::InnerType,
+        _validation: &Validation<Self::Validator>,
+    ) -> TokenStream {
+        quote!(&#inner_type)
+    }
+
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        report: &ValidationReport,
+        error_variants: ErrorVariants,
         const_fn: ConstFn,
     ) -> TokenStream;
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        error_variants: ErrorVariants,
     ) -> TokenStream;
 
+    /// If the generated validation error type implements `Eq`.
+    fn is_validation_error_eq(_error_variants: ErrorVariants) -> bool {
+        true
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn gen_traits(
         type_name: &TypeName,
@@ -246,10 +483,29 @@ pub trait GenerateNewtype {
             Validation::Standard {
                 validators,
                 error_type_path,
+                report,
+                error_variants,
             } => {
-                let validation_error =
-                    Self::gen_validation_error_type(type_name, error_type_path, validators);
-                Some(validation_error)
+                let validation_error = Self::gen_validation_error_type(
+                    type_name,
+                    inner_type,
+                    error_type_path,
+                    validators,
+                    *error_variants,
+                );
+                let maybe_validation_errors = match report {
+                    ValidationReport::First => None,
+                    ValidationReport::All { errors_type_path } => Some(gen_validation_errors_type(
+                        type_name,
+                        error_type_path,
+                        errors_type_path,
+                        Self::is_validation_error_eq(*error_variants),
+                    )),
+                };
+                Some(quote! {
+                    #validation_error
+                    #maybe_validation_errors
+                })
             }
             Validation::Custom { .. } => None,
         };
@@ -258,7 +514,16 @@ pub trait GenerateNewtype {
             Validation::Standard {
                 validators,
                 error_type_path,
-            } => Self::gen_fn_validate(inner_type, error_type_path, validators, const_fn),
+                report,
+                error_variants,
+            } => Self::gen_fn_validate(
+                inner_type,
+                error_type_path,
+                validators,
+                report,
+                *error_variants,
+                const_fn,
+            ),
             Validation::Custom {
                 with,
                 error_type_path,
@@ -275,6 +540,19 @@ pub trait GenerateNewtype {
         };
 
         let error_type_path = validation.error_type_path();
+        let doc = gen_try_new_doc(
+            &Self::sanitizer_kinds(sanitizers),
+            &Self::gen_validator_rules(validation),
+            validation,
+        );
+        let fn_sanitize_public =
+            gen_fn_sanitize_public(inner_type, const_fn, constructor_visibility);
+        let fns_validate_public = gen_fns_validate_public(
+            &Self::gen_validate_param_type(inner_type, validation),
+            validation,
+            const_fn,
+            constructor_visibility,
+        );
 
         // Example for `struct Wrapper<T: Clone>(T) where T: Default`:
         //
@@ -285,6 +563,7 @@ pub trait GenerateNewtype {
             #maybe_generated_validation_error
 
             impl #impl_generics #type_name #type_generics #where_clause {
+                #doc
                 #constructor_visibility #const_fn fn try_new(raw_value: #input_type) -> ::core::result::Result<Self, #error_type_path> {
                     #convert_raw_value_if_necessary
 
@@ -299,6 +578,9 @@ pub trait GenerateNewtype {
                     Ok(#type_name(sanitized_value))
                 }
 
+                #fn_sanitize_public
+                #fns_validate_public
+
                 // Definite associated private functions __sanitize__() and __validate__() with underscores so they do not overlap with outer
                 // scope imported with `use super::*`.
                 #fn_sanitize
@@ -330,6 +612,9 @@ pub trait GenerateNewtype {
         } else {
             (quote!(#inner_type), quote!())
         };
+        let doc = gen_new_doc(&Self::sanitizer_kinds(sanitizers));
+        let fn_sanitize_public =
+            gen_fn_sanitize_public(inner_type, const_fn, constructor_visibility);
 
         // Example for `struct Wrapper<T: Clone>(T) where T: Default`:
         //
@@ -338,10 +623,13 @@ pub trait GenerateNewtype {
         // }
         quote!(
             impl #impl_generics #type_name #type_generics #where_clause {
+                #doc
                 #constructor_visibility #const_fn fn new(raw_value: #input_type) -> Self {
                     #convert_raw_value_if_necessary
                     Self(Self::__sanitize__(raw_value))
                 }
+
+                #fn_sanitize_public
                 // Definite associated private function __sanitize__() with underscores so they do not overlap with outer
                 // scope imported with `use super::*`.
                 #fn_sanitize
@@ -380,7 +668,23 @@ pub trait GenerateNewtype {
                 constructor_visibility,
             ),
         };
+        let extra_constructors =
+            Self::gen_extra_constructors(type_name, guard, constructor_visibility);
         let impl_into_inner = gen_impl_into_inner(type_name, generics, inner_type, const_fn);
+        let constraints = Self::gen_constraints(inner_type, guard);
+        let impl_constraints = gen_impl_constraints(type_name, generics, &constraints);
+        let extra_consts = Self::gen_extra_consts(type_name, guard);
+        let impl_nutype_trait = if Self::IMPLEMENTS_NUTYPE_TRAIT {
+            gen_impl_nutype_trait(
+                type_name,
+                generics,
+                inner_type,
+                guard,
+                constructor_visibility,
+            )
+        } else {
+            quote!()
+        };
         let impl_new_unchecked = gen_new_unchecked(
             type_name,
             inner_type,
@@ -391,8 +695,12 @@ pub trait GenerateNewtype {
 
         quote! {
             #impl_new
+            #extra_constructors
             #impl_into_inner
             #impl_new_unchecked
+            #impl_constraints
+            #extra_consts
+            #impl_nutype_trait
         }
     }
 
@@ -418,9 +726,27 @@ pub trait GenerateNewtype {
             inner_type,
             generics,
             conditional_derives,
+            derived_trait_names,
         } = params;
 
         let module_name = gen_module_name_for_type(&type_name);
+        let generated_doc = {
+            let sanitizers = match &guard {
+                Guard::WithoutValidation { sanitizers }
+                | Guard::WithValidation { sanitizers, .. } => sanitizers,
+            };
+            let maybe_validation = guard.maybe_validation();
+            let validator_rules = maybe_validation
+                .map(Self::gen_validator_rules)
+                .unwrap_or_default();
+            gen_type_doc(
+                !doc_attrs.is_empty(),
+                &Self::sanitizer_kinds(sanitizers),
+                &validator_rules,
+                maybe_validation,
+                &derived_trait_names,
+            )
+        };
         let implementation = Self::gen_implementation(
             &type_name,
             &generics,
@@ -431,6 +757,9 @@ pub trait GenerateNewtype {
             &constructor_visibility,
         );
 
+        let extra_methods =
+            Self::gen_extra_methods(&type_name, &guard, &gen_visibility_within_module(&vis));
+
         let has_from_str_trait = traits.iter().any(|t| t.is_from_str());
         let maybe_parse_error_type_path = if has_from_str_trait && Self::HAS_DEDICATED_PARSE_ERROR {
             Some(gen_parse_error_name(&type_name))
@@ -447,14 +776,21 @@ pub trait GenerateNewtype {
             &traits,
         );
 
-        let maybe_reimported_error_type_path = match &guard {
-            Guard::WithoutValidation { .. } => None,
+        let reimported_error_type_paths: Vec<&ErrorTypePath> = match &guard {
+            Guard::WithoutValidation { .. } => vec![],
             Guard::WithValidation { validation, .. } => match validation {
                 // We won't need to reimport error if it's a custom error provided by the user.
-                Validation::Custom { .. } => None,
+                Validation::Custom { .. } => vec![],
                 Validation::Standard {
-                    error_type_path, ..
-                } => Some(error_type_path),
+                    error_type_path,
+                    report,
+                    ..
+                } => match report {
+                    ValidationReport::First => vec![error_type_path],
+                    ValidationReport::All { errors_type_path } => {
+                        vec![error_type_path, errors_type_path]
+                    }
+                },
             },
         };
 
@@ -479,7 +815,7 @@ pub trait GenerateNewtype {
             vis,
             &type_name,
             &module_name,
-            maybe_reimported_error_type_path,
+            &reimported_error_type_paths,
             maybe_parse_error_type_path.as_ref(),
             &conditional_from_str_parse_errors,
         );
@@ -502,11 +838,13 @@ pub trait GenerateNewtype {
                 use super::*;
 
                 #(#doc_attrs)*
+                #generated_doc
                 #derive_transparent_traits
                 #conditional_derive_transparent_traits
                 pub struct #type_name #struct_generics (#inner_type) #struct_where_clause;
 
                 #implementation
+                #extra_methods
                 #implement_traits
                 #conditional_implement_traits
 
@@ -545,3 +883,31 @@ fn gen_fn_validate_custom<InnerType: ToTokens>(
         }
     }
 }
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_gen_visibility_within_module() {
+        let cases: [(Visibility, TokenStream); 7] = [
+            (parse_quote!(pub), quote!(pub)),
+            (Visibility::Inherited, quote!(pub(super))),
+            (parse_quote!(pub(crate)), quote!(pub(in crate))),
+            (parse_quote!(pub(self)), quote!(pub(in super))),
+            (parse_quote!(pub(super)), quote!(pub(in super::super))),
+            (parse_quote!(pub(in crate::a)), quote!(pub(in crate::a))),
+            (
+                parse_quote!(pub(in super::a)),
+                quote!(pub(in super::super::a)),
+            ),
+        ];
+        for (vis, expected) in cases {
+            assert_eq!(
+                gen_visibility_within_module(&vis).to_string(),
+                expected.to_string()
+            );
+        }
+    }
+}
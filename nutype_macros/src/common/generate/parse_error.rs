@@ -4,8 +4,11 @@ use quote::{format_ident, quote};
 use syn::Generics;
 
 use crate::common::{
-    generate::generics::{SplitGenerics, add_bound_to_all_type_params},
-    models::{ErrorTypePath, InnerType, ParseErrorTypeName, TypeName},
+    generate::{
+        error::gen_rule_from_tokens,
+        generics::{SplitGenerics, add_bound_to_all_type_params},
+    },
+    models::{InnerType, ParseErrorTypeName, TypeName, Validation},
 };
 
 /// Generate a name for the error which is used for FromStr trait implementation.
@@ -16,15 +19,16 @@ pub fn gen_parse_error_name(type_name: &TypeName) -> ParseErrorTypeName {
 
 /// Generate an error which is used for FromStr trait implementation of non-string types (e.g.
 /// floats or integers)
-pub fn gen_def_parse_error(
+pub fn gen_def_parse_error<Validator>(
     type_name: &TypeName,
     generics: &Generics,
     inner_type: impl Into<InnerType>,
-    maybe_error_type_name: Option<&ErrorTypePath>,
+    maybe_validation: Option<&Validation<Validator>>,
     parse_error_type_name: &ParseErrorTypeName,
 ) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
     let type_name_str = type_name.to_string();
+    let maybe_error_type_name = maybe_validation.map(Validation::error_type_path);
 
     let generics_with_fromstr_bound = add_bound_to_all_type_params(
         generics,
@@ -110,8 +114,74 @@ pub fn gen_def_parse_error(
         }
     };
 
+    let impl_validation_error = gen_impl_validation_error_trait(
+        type_name,
+        &generics_with_fromstr_bound,
+        maybe_validation,
+        parse_error_type_name,
+    );
+
     quote! {
         #definition
         #impl_error
+        #impl_validation_error
+    }
+}
+
+fn gen_impl_validation_error_trait<Validator>(
+    type_name: &TypeName,
+    generics_with_fromstr_bound: &Generics,
+    maybe_validation: Option<&Validation<Validator>>,
+    parse_error_type_name: &ParseErrorTypeName,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+
+    // `ValidationError` requires `Debug`, which is derived only if all type params are `Debug`.
+    let generics_with_fromstr_and_debug_bounds = add_bound_to_all_type_params(
+        generics_with_fromstr_bound,
+        syn::parse_quote!(::core::fmt::Debug),
+    );
+    let SplitGenerics {
+        impl_generics,
+        type_generics,
+        where_clause,
+    } = SplitGenerics::new(&generics_with_fromstr_and_debug_bounds);
+
+    let (validate_code_arm, validate_rule_arm) = match maybe_validation {
+        None => (quote!(), quote!()),
+        Some(Validation::Standard { .. }) => (
+            quote!(#parse_error_type_name::Validate(err) => ::nutype::ValidationError::code(err),),
+            quote!(#parse_error_type_name::Validate(err) => ::nutype::ValidationError::rule(err),),
+        ),
+        // Errors of custom validation are defined by the user and do not implement the trait.
+        Some(Validation::Custom { with, .. }) => {
+            let rule = gen_rule_from_tokens("with", with);
+            (
+                quote!(#parse_error_type_name::Validate(_) => "with",),
+                quote!(#parse_error_type_name::Validate(_) => #rule,),
+            )
+        }
+    };
+
+    quote! {
+        impl #impl_generics ::nutype::ValidationError for #parse_error_type_name #type_generics #where_clause {
+            fn code(&self) -> &'static str {
+                match self {
+                    #parse_error_type_name::Parse(_) => "parse",
+                    #validate_code_arm
+                }
+            }
+
+            fn type_name(&self) -> &'static str {
+                #type_name_str
+            }
+
+            fn rule(&self) -> &'static str {
+                match self {
+                    #parse_error_type_name::Parse(_) => "parse",
+                    #validate_rule_arm
+                }
+            }
+        }
     }
 }
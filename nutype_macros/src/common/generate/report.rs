@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::common::{generate::error::gen_vec_path, models::ValidationReport};
+
+/// Generate a statement that is executed when a single validation rule is violated.
+///
+/// With `report = first` (default) the violation is returned immediately,
+/// with `report = all` it's pushed into the list of errors and the validation continues.
+pub fn gen_report_violation(report: &ValidationReport, violation: TokenStream) -> TokenStream {
+    match report {
+        ValidationReport::First => quote!(
+            return Err(#violation);
+        ),
+        ValidationReport::All { .. } => quote!(
+            __nutype_errors__.push(#violation);
+        ),
+    }
+}
+
+/// Wrap the validation checks (generated with `gen_report_violation()`), so they produce
+/// the result of `__validate__()` function.
+pub fn gen_validation_result(report: &ValidationReport, validations: TokenStream) -> TokenStream {
+    match report {
+        ValidationReport::First => quote!(
+            #validations
+            Ok(())
+        ),
+        ValidationReport::All { errors_type_path } => {
+            let vec = gen_vec_path();
+            quote!(
+                // Make up a sufficiently unique name to ensure that it does not clash
+                // with anything imported with `use super::*`.
+                let mut __nutype_errors__ = #vec::new();
+                #validations
+                if __nutype_errors__.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#errors_type_path(__nutype_errors__))
+                }
+            )
+        }
+    }
+}
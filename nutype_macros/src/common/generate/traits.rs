@@ -7,7 +7,9 @@ use syn::Generics;
 
 use crate::common::{
     generate::generics::{SplitGenerics, add_bound_to_all_type_params},
-    models::{ConditionalDeriveGroup, ErrorTypePath, InnerType, ParseErrorTypeName, TypeName},
+    models::{
+        ConditionalDeriveGroup, ErrorTypePath, InnerType, ParseErrorTypeName, TypeName, Validation,
+    },
 };
 
 use super::parse_error::{gen_def_parse_error, gen_parse_error_name};
@@ -364,19 +366,20 @@ pub fn gen_impl_trait_try_from(
 }
 
 /// Generate implementation of FromStr trait for non-string types (e.g. integers or floats).
-pub fn gen_impl_trait_from_str(
+pub fn gen_impl_trait_from_str<Validator>(
     type_name: &TypeName,
     generics: &Generics,
     inner_type: impl Into<InnerType>,
-    maybe_error_type_name: Option<&ErrorTypePath>,
+    maybe_validation: Option<&Validation<Validator>>,
 ) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
+    let maybe_error_type_name = maybe_validation.map(Validation::error_type_path);
     let parse_error_type_name = gen_parse_error_name(type_name);
     let def_parse_error = gen_def_parse_error(
         type_name,
         generics,
         inner_type.clone(),
-        maybe_error_type_name,
+        maybe_validation,
         &parse_error_type_name,
     );
 
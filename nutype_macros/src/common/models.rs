@@ -227,27 +227,66 @@ pub enum Validation<Validator> {
 
         /// Name of the error type. The #[nutype] macro must generate definition of this type.
         error_type_path: ErrorTypePath,
+
+        /// Defines whether validation stops at the first violation or collects all of them.
+        report: ValidationReport,
+
+        /// Defines whether error variants carry the violated bound and the actual value.
+        error_variants: ErrorVariants,
     },
 }
 
 impl<V> Validation<V> {
+    /// Name of the error type returned by `try_new()`.
     pub fn error_type_path(&self) -> &ErrorTypePath {
         match self {
             Self::Custom {
                 error_type_path, ..
             } => error_type_path,
             Self::Standard {
-                error_type_path, ..
-            } => error_type_path,
+                error_type_path,
+                report,
+                ..
+            } => report.returned_error_type_path(error_type_path),
+        }
+    }
+}
+
+/// Validated `report = ...` attribute of standard validation.
+#[derive(Debug)]
+pub enum ValidationReport {
+    /// Validation returns the first violated rule (the default).
+    First,
+
+    /// Validation checks every rule and returns all the violations at once.
+    All {
+        /// Name of the generated collection type, that wraps the individual errors.
+        errors_type_path: ErrorTypePath,
+    },
+}
+
+impl ValidationReport {
+    /// Given the name of the error enum, returns the name of the error type returned by `try_new()`.
+    pub fn returned_error_type_path<'a>(
+        &'a self,
+        error_type_path: &'a ErrorTypePath,
+    ) -> &'a ErrorTypePath {
+        match self {
+            Self::First => error_type_path,
+            Self::All { errors_type_path } => errors_type_path,
         }
     }
 }
 
 impl<Sanitizer, Validator> Guard<Sanitizer, Validator> {
     pub fn maybe_error_type_path(&self) -> Option<&ErrorTypePath> {
+        self.maybe_validation().map(Validation::error_type_path)
+    }
+
+    pub fn maybe_validation(&self) -> Option<&Validation<Validator>> {
         match self {
             Self::WithoutValidation { .. } => None,
-            Self::WithValidation { validation, .. } => Some(validation.error_type_path()),
+            Self::WithValidation { validation, .. } => Some(validation),
         }
     }
 }
@@ -489,6 +528,13 @@ pub struct ConditionalDeriveGroup<TypedTrait> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeriveUnsafeTrait(::syn::Path);
 
+impl DeriveUnsafeTrait {
+    /// Path of the trait without whitespaces, e.g. `serde::Serialize`.
+    pub fn name(&self) -> String {
+        self.0.to_token_stream().to_string().replace(' ', "")
+    }
+}
+
 impl ToTokens for DeriveUnsafeTrait {
     fn to_tokens(&self, token_stream: &mut TokenStream) {
         self.0.to_tokens(token_stream);
@@ -528,6 +574,29 @@ pub enum NewUnchecked {
     On,
 }
 
+/// Parsed from `report = ...` within `validate(...)` attribute.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportMode {
+    /// Stop at the first violation
+    #[default]
+    First,
+
+    /// Collect all the violations
+    All,
+}
+
+/// Shape of the generated error variants.
+/// Parsed from `detailed_errors` flag within `validate(...)` attribute.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorVariants {
+    /// Unit variants, e.g. `LenCharMaxViolated`
+    #[default]
+    Unit,
+
+    /// Struct-like variants, e.g. `LenCharMaxViolated { max: usize, actual: usize }`
+    Detailed,
+}
+
 /// The flag that indicates the functions must be generated with `const` keyword.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum ConstFn {
@@ -599,6 +668,18 @@ pub struct GenerateParams<IT, Trait, Guard> {
     pub maybe_default_value: Option<syn::Expr>,
     /// Conditional derive groups, one per predicate.
     pub conditional_derives: Vec<ConditionalDeriveGroup<Trait>>,
+    /// Names of the derived traits. Used for the generated documentation.
+    pub derived_trait_names: DerivedTraitNames,
+}
+
+/// Names of the derived traits in the order of declaration.
+#[derive(Debug, Default)]
+pub struct DerivedTraitNames {
+    /// Traits from `derive(...)` and `derive_unchecked(...)`.
+    pub unconditional: Vec<String>,
+
+    /// Traits from `cfg_attr(...)` entries: the predicate and the traits derived under it.
+    pub conditional: Vec<(String, Vec<String>)>,
 }
 
 pub trait Newtype {
@@ -652,6 +733,34 @@ pub trait Newtype {
             cfg_attr_entries,
         } = Self::parse_attributes(attrs, &type_name)?;
 
+        let derived_trait_names = DerivedTraitNames {
+            unconditional: derive_traits
+                .iter()
+                .map(|spanned_trait| spanned_trait.item.to_string())
+                .chain(
+                    derive_unchecked_traits
+                        .iter()
+                        .map(|spanned_trait| spanned_trait.item.name()),
+                )
+                .collect(),
+            conditional: cfg_attr_entries
+                .iter()
+                .map(|entry| {
+                    let names = match &entry.content {
+                        CfgAttrContent::Derive(traits) => traits
+                            .iter()
+                            .map(|spanned_trait| spanned_trait.item.to_string())
+                            .collect(),
+                        CfgAttrContent::DeriveUnchecked(traits) => traits
+                            .iter()
+                            .map(|spanned_trait| spanned_trait.item.name())
+                            .collect(),
+                    };
+                    (entry.predicate.to_string(), names)
+                })
+                .collect(),
+        };
+
         let validated = Self::validate(
             &guard,
             derive_traits,
@@ -677,6 +786,7 @@ pub trait Newtype {
             maybe_default_value,
             inner_type,
             conditional_derives,
+            derived_trait_names,
         })?;
         Ok(generated_output)
     }
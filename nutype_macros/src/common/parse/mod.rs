@@ -22,8 +22,8 @@ use crate::common::models::{
 };
 
 use super::models::{
-    ConstFn, ConstructorVisibility, CustomFunction, ErrorTypePath, NewUnchecked,
-    TypedCustomFunction, ValueOrExpr,
+    ConstFn, ConstructorVisibility, CustomFunction, ErrorTypePath, ErrorVariants, NewUnchecked,
+    ReportMode, SpannedItem, TypedCustomFunction, ValueOrExpr,
 };
 
 pub fn is_doc_attribute(attribute: &syn::Attribute) -> bool {
@@ -95,17 +95,19 @@ enum ValidateAttr<Validator: Parse + Kinded> {
 }
 
 /// Non standard (custom) validation attributes.
-/// Responsible for parsing `error` and `with` attributes.
+/// Responsible for parsing `error`, `with`, `report` and `detailed_errors` attributes.
 #[derive(Debug, Kinded)]
 #[kinded(display = "snake_case")]
 enum ExtraValidateAttr {
     Error(ErrorTypePath),
     With(CustomFunction),
+    Report(SpannedItem<ReportMode>),
+    DetailedErrors(Span),
 }
 
 impl Parse for ExtraValidateAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_validator_kind(input)?;
+        let (kind, ident) = parse_validator_kind(input)?;
         match kind {
             ExtraValidateAttrKind::Error => {
                 let _eq: Token![=] = input.parse()?;
@@ -117,6 +119,27 @@ impl Parse for ExtraValidateAttr {
                 let custom_function: CustomFunction = input.parse()?;
                 Ok(ExtraValidateAttr::With(custom_function))
             }
+            ExtraValidateAttrKind::Report => {
+                let _eq: Token![=] = input.parse()?;
+                let mode_ident: Ident = input.parse()?;
+                let mode = if mode_ident == "first" {
+                    ReportMode::First
+                } else if mode_ident == "all" {
+                    ReportMode::All
+                } else {
+                    let msg = format!(
+                        "Unknown report mode: `{mode_ident}`.\nPossible values are `first` and `all`."
+                    );
+                    return Err(syn::Error::new(mode_ident.span(), msg));
+                };
+                Ok(ExtraValidateAttr::Report(SpannedItem::new(
+                    mode,
+                    mode_ident.span(),
+                )))
+            }
+            ExtraValidateAttrKind::DetailedErrors => {
+                Ok(ExtraValidateAttr::DetailedErrors(ident.span()))
+            }
         }
     }
 }
@@ -139,7 +162,10 @@ where
             .as_deref()
             .map(|n| n.parse::<<Validator as Kinded>::Kind>().is_ok())
             .unwrap_or(false);
-        let is_extra = matches!(ident_name.as_deref(), Some("with") | Some("error"));
+        let is_extra = matches!(
+            ident_name.as_deref(),
+            Some("with") | Some("error") | Some("report") | Some("detailed_errors")
+        );
 
         if is_known_validator {
             let validator: Validator = input.parse()?;
@@ -152,7 +178,11 @@ where
                 .iter()
                 .map(|k| format!("`{k}`"))
                 .filter(|s| s != "`_phantom`") // filter out _Phantom variant
-                .chain(["`with`", "`error`"].iter().map(|s| s.to_string()))
+                .chain(
+                    ["`with`", "`error`", "`report`", "`detailed_errors`"]
+                        .iter()
+                        .map(|s| s.to_string()),
+                )
                 .collect::<Vec<_>>()
                 .join(", ");
             let ident: Ident = input.parse()?;
@@ -172,6 +202,8 @@ pub enum RawValidation<Validator> {
     },
     Standard {
         validators: Vec<Validator>,
+        report: ReportMode,
+        error_variants: ErrorVariants,
     },
 }
 
@@ -187,6 +219,8 @@ where
         let mut validators: Vec<Validator> = Vec::new();
         let mut maybe_with: Option<CustomFunction> = None;
         let mut maybe_error: Option<ErrorTypePath> = None;
+        let mut maybe_report: Option<SpannedItem<ReportMode>> = None;
+        let mut maybe_detailed_errors: Option<Span> = None;
 
         for attr in attrs {
             match attr {
@@ -208,10 +242,39 @@ where
                         }
                         maybe_with = Some(with);
                     }
+                    ExtraValidateAttr::Report(report) => {
+                        if maybe_report.is_some() {
+                            let msg = "Duplicate `report` attribute";
+                            return Err(syn::Error::new(report.span(), msg));
+                        }
+                        maybe_report = Some(report);
+                    }
+                    ExtraValidateAttr::DetailedErrors(span) => {
+                        if maybe_detailed_errors.is_some() {
+                            let msg = "Duplicate `detailed_errors` attribute";
+                            return Err(syn::Error::new(span, msg));
+                        }
+                        maybe_detailed_errors = Some(span);
+                    }
                 },
             }
         }
 
+        if let (Some(report), Some(_)) = (&maybe_report, &maybe_with) {
+            let msg = "The `report` attribute cannot be used with custom `with` validation.\nThe custom validation function decides itself which error to return.";
+            return Err(syn::Error::new(report.span(), msg));
+        }
+        if let (Some(span), Some(_)) = (maybe_detailed_errors, &maybe_with) {
+            let msg = "The `detailed_errors` attribute cannot be used with custom `with` validation.\nThe custom validation function defines the error type itself.";
+            return Err(syn::Error::new(span, msg));
+        }
+        let report = maybe_report.map(|r| r.item).unwrap_or_default();
+        let error_variants = if maybe_detailed_errors.is_some() {
+            ErrorVariants::Detailed
+        } else {
+            ErrorVariants::Unit
+        };
+
         match (validators.len(), maybe_with, maybe_error) {
             (0, Some(with), Some(error)) => Ok(RawValidation::Custom { with, error }),
             (0, Some(_), None) => {
@@ -228,7 +291,11 @@ where
                 let msg = "At least one validator must be specified";
                 Err(syn::Error::new(input.span(), msg))
             }
-            (_, None, None) => Ok(RawValidation::Standard { validators }),
+            (_, None, None) => Ok(RawValidation::Standard {
+                validators,
+                report,
+                error_variants,
+            }),
             (_, _, _) => {
                 let msg =
                     "`with` and `error` attributes cannot be used mixed with other validators.";
@@ -263,6 +330,7 @@ where
 {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut attrs = ParseableAttributes::default();
+        let mut maybe_const_fn_span: Option<Span> = None;
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
@@ -314,6 +382,7 @@ where
                 attrs.default = Some(default_expr);
             } else if ident == "const_fn" {
                 attrs.const_fn = ConstFn::Const;
+                maybe_const_fn_span = Some(ident.span());
             } else if ident == "new_unchecked" {
                 cfg_if! {
                     if #[cfg(feature = "new_unchecked")] {
@@ -395,6 +464,18 @@ where
             }
         }
 
+        if let (
+            Some(const_fn_span),
+            Some(RawValidation::Standard {
+                report: ReportMode::All,
+                ..
+            }),
+        ) = (maybe_const_fn_span, &attrs.validation)
+        {
+            let msg = "`const_fn` cannot be used with `report = all`.\nCollecting all the violations requires heap allocation, which is not possible in const functions.";
+            return Err(syn::Error::new(const_fn_span, msg));
+        }
+
         Ok(attrs)
     }
 }
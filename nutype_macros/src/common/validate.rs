@@ -4,11 +4,11 @@ use proc_macro2::Span;
 use std::collections::HashSet;
 
 use super::{
-    r#generate::error::gen_error_type_name,
+    r#generate::error::{gen_error_type_name, gen_errors_type_name},
     models::{
         CfgAttrContent, CfgAttrEntry, DeriveTrait, Guard, NumericBoundValidator, RawGuard,
-        SpannedDeriveTrait, SpannedItem, TypeName, TypeTrait, ValidatedCfgAttrDerives,
-        ValidatedDerives, Validation,
+        ReportMode, SpannedDeriveTrait, SpannedItem, TypeName, TypeTrait, ValidatedCfgAttrDerives,
+        ValidatedDerives, Validation, ValidationReport,
     },
     parse::RawValidation,
 };
@@ -32,12 +32,24 @@ pub fn validate_guard<RawSanitizer, RawValidator, Sanitizer, Validator>(
     };
 
     let validation = match raw_validation {
-        RawValidation::Standard { validators } => {
+        RawValidation::Standard {
+            validators,
+            report,
+            error_variants,
+        } => {
             let error_type_path = gen_error_type_name(type_name);
             let validators = validate_validators(validators)?;
+            let report = match report {
+                ReportMode::First => ValidationReport::First,
+                ReportMode::All => ValidationReport::All {
+                    errors_type_path: gen_errors_type_name(type_name),
+                },
+            };
             Validation::Standard {
                 validators,
                 error_type_path,
+                report,
+                error_variants,
             }
         }
         RawValidation::Custom { with, error } => {
@@ -1,63 +1,111 @@
+use core::fmt::Debug;
+
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
 use crate::common::{
-    generate::error::gen_impl_error_trait,
-    models::{ErrorTypePath, TypeName},
+    generate::{
+        doc::gen_error_variant_doc,
+        error::{
+            ViolatedRule, gen_error_variant, gen_impl_error_trait, gen_impl_validation_error_trait,
+            gen_rule,
+        },
+    },
+    models::{ErrorTypePath, ErrorVariants, TypeName},
 };
 
 use super::super::models::FloatValidator;
 
-pub fn gen_validation_error_type<T: ToTokens>(
+pub fn gen_validation_error_type<T: ToTokens + Debug>(
     type_name: &TypeName,
+    inner_type: impl ToTokens,
     error_type_path: &ErrorTypePath,
     validators: &[FloatValidator<T>],
+    error_variants: ErrorVariants,
 ) -> TokenStream {
-    let definition = gen_definition(error_type_path, validators);
+    let definition = gen_definition(inner_type, error_type_path, validators, error_variants);
     let impl_display_trait = gen_impl_display_trait(type_name, error_type_path, validators);
     let impl_error_trait = gen_impl_error_trait(error_type_path);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        error_type_path,
+        &gen_violated_rules(error_type_path, validators),
+    );
+
+    // Detailed error variants carry float values, which do not implement `Eq`.
+    let maybe_eq = match error_variants {
+        ErrorVariants::Unit => quote!(, Eq),
+        ErrorVariants::Detailed => quote!(),
+    };
 
     quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[derive(Debug, Clone, PartialEq #maybe_eq)]
         #definition
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
-fn gen_definition<T>(
+fn gen_definition<T: Debug>(
+    inner_type: impl ToTokens,
     error_type_path: &ErrorTypePath,
     validators: &[FloatValidator<T>],
+    error_variants: ErrorVariants,
 ) -> TokenStream {
-    let error_variants: TokenStream = validators
+    let variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            FloatValidator::Greater(_) => {
-                quote!(GreaterViolated,)
-            }
-            FloatValidator::GreaterOrEqual(_) => {
-                quote!(GreaterOrEqualViolated,)
-            }
-            FloatValidator::LessOrEqual(_) => {
-                quote!(LessOrEqualViolated,)
-            }
-            FloatValidator::Less(_) => {
-                quote!(LessViolated,)
-            }
-            FloatValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
-            FloatValidator::Finite => {
-                quote!(FiniteViolated,)
-            }
+        .map(|validator| {
+            let doc = gen_error_variant_doc(&gen_validator_rule(validator));
+            let variant = match validator {
+                FloatValidator::Greater(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(GreaterViolated),
+                        quote!(min: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                FloatValidator::GreaterOrEqual(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(GreaterOrEqualViolated),
+                        quote!(min: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                FloatValidator::LessOrEqual(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LessOrEqualViolated),
+                        quote!(max: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                FloatValidator::Less(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LessViolated),
+                        quote!(max: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                FloatValidator::Predicate(_) => {
+                    quote!(PredicateViolated,)
+                }
+                FloatValidator::Finite => {
+                    quote!(FiniteViolated,)
+                }
+            };
+            quote!(#doc #variant)
         })
         .collect();
 
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_path {
-            #error_variants
+            #variants
         }
     }
 }
@@ -69,16 +117,16 @@ fn gen_impl_display_trait<T: ToTokens>(
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
         FloatValidator::Greater(val) => quote! {
-             #error_type_path::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::GreaterViolated { .. } => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
         },
         FloatValidator::GreaterOrEqual(val) => quote! {
-             #error_type_path::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::GreaterOrEqualViolated { .. } => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
         },
         FloatValidator::LessOrEqual(val) => quote! {
-             #error_type_path::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::LessOrEqualViolated { .. } => write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
         },
         FloatValidator::Less(val) => quote! {
-             #error_type_path::LessViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::LessViolated { .. } => write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
         },
         FloatValidator::Predicate(_) => quote! {
              #error_type_path::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
@@ -98,3 +146,43 @@ fn gen_impl_display_trait<T: ToTokens>(
         }
     }
 }
+
+fn gen_violated_rules<T: Debug>(
+    error_type_path: &ErrorTypePath,
+    validators: &[FloatValidator<T>],
+) -> Vec<ViolatedRule> {
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = match validator {
+                FloatValidator::Greater(_) => quote!(#error_type_path::GreaterViolated { .. }),
+                FloatValidator::GreaterOrEqual(_) => {
+                    quote!(#error_type_path::GreaterOrEqualViolated { .. })
+                }
+                FloatValidator::Less(_) => quote!(#error_type_path::LessViolated { .. }),
+                FloatValidator::LessOrEqual(_) => {
+                    quote!(#error_type_path::LessOrEqualViolated { .. })
+                }
+                FloatValidator::Predicate(_) => quote!(#error_type_path::PredicateViolated),
+                FloatValidator::Finite => quote!(#error_type_path::FiniteViolated),
+            };
+            ViolatedRule {
+                pattern,
+                code: validator.kind().to_string(),
+                rule: gen_validator_rule(validator),
+            }
+        })
+        .collect()
+}
+
+/// Describes the validator as it's declared, e.g. `greater = 0`.
+pub fn gen_validator_rule<T: Debug>(validator: &FloatValidator<T>) -> TokenStream {
+    let code = validator.kind().to_string();
+    match validator {
+        FloatValidator::Greater(val)
+        | FloatValidator::GreaterOrEqual(val)
+        | FloatValidator::Less(val)
+        | FloatValidator::LessOrEqual(val) => gen_rule(&code, val),
+        FloatValidator::Predicate(_) | FloatValidator::Finite => quote!(#code),
+    }
+}
@@ -16,6 +16,9 @@ use crate::{
     common::{
         generate::{
             GenerateNewtype,
+            constraints::{gen_bound_constraint_value, gen_validator_constraint},
+            error::gen_error_variant,
+            report::{gen_report_violation, gen_validation_result},
             tests::{
                 gen_test_should_have_consistent_lower_and_upper_boundaries,
                 gen_test_should_have_valid_default_value,
@@ -23,8 +26,8 @@ use crate::{
             traits::GeneratedTraits,
         },
         models::{
-            ConditionalDeriveGroup, ConstFn, ErrorTypePath, Guard, SpannedDeriveUnsafeTrait,
-            TypeName,
+            ConditionalDeriveGroup, ConstFn, ErrorTypePath, ErrorVariants, Guard,
+            SpannedDeriveUnsafeTrait, TypeName, ValidationReport,
         },
     },
     float::models::FloatInnerType,
@@ -67,75 +70,152 @@ where
         )
     }
 
+    fn sanitizer_kind(sanitizer: &Self::Sanitizer) -> String {
+        match sanitizer {
+            FloatSanitizer::With(_) => "with".to_string(),
+            FloatSanitizer::_Phantom(_) => {
+                unreachable!("float::gen: FloatSanitizer::_Phantom must not be used")
+            }
+        }
+    }
+
+    fn gen_validator_rule(validator: &Self::Validator) -> TokenStream {
+        error::gen_validator_rule(validator)
+    }
+
+    fn gen_validator_constraint(
+        _inner_type: &Self::InnerType,
+        validator: &Self::Validator,
+    ) -> TokenStream {
+        let kind = validator.kind().to_string();
+        let maybe_value = match validator {
+            FloatValidator::Greater(bound)
+            | FloatValidator::GreaterOrEqual(bound)
+            | FloatValidator::Less(bound)
+            | FloatValidator::LessOrEqual(bound) => Some(gen_bound_constraint_value(
+                bound,
+                quote!(Float),
+                quote!(f64),
+            )),
+            FloatValidator::Predicate(_) | FloatValidator::Finite => None,
+        };
+        gen_validator_constraint(&kind, maybe_value)
+    }
+
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        report: &ValidationReport,
+        error_variants: ErrorVariants,
         const_fn: ConstFn,
     ) -> TokenStream {
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
                 FloatValidator::Less(exclusive_upper_bound) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LessViolated),
+                        quote!(max: #exclusive_upper_bound, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val >= #exclusive_upper_bound {
-                            return Err(#error_type_path::LessViolated);
+                            #violation
                         }
                     )
                 }
                 FloatValidator::LessOrEqual(max) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LessOrEqualViolated),
+                        quote!(max: #max, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val > #max {
-                            return Err(#error_type_path::LessOrEqualViolated);
+                            #violation
                         }
                     )
                 }
                 FloatValidator::Greater(exclusive_lower_bound) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::GreaterViolated),
+                        quote!(min: #exclusive_lower_bound, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val <= #exclusive_lower_bound {
-                            return Err(#error_type_path::GreaterViolated);
+                            #violation
                         }
                     )
                 }
                 FloatValidator::GreaterOrEqual(min) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::GreaterOrEqualViolated),
+                        quote!(min: #min, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val < #min {
-                            return Err(#error_type_path::GreaterOrEqualViolated);
+                            #violation
                         }
                     )
                 }
                 FloatValidator::Predicate(custom_is_valid_fn) => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::PredicateViolated));
                     quote!(
                         if !(#custom_is_valid_fn)(&val) {
-                            return Err(#error_type_path::PredicateViolated);
+                            #violation
                         }
                     )
                 }
                 FloatValidator::Finite => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::FiniteViolated));
                     quote!(
                         if !val.is_finite() {
-                            return Err(#error_type_path::FiniteViolated);
+                            #violation
                         }
                     )
                 }
             })
             .collect();
 
+        let validation_result = gen_validation_result(report, validations);
+        let returned_error_type_path = report.returned_error_type_path(error_type_path);
+
         quote!(
-            #const_fn fn __validate__(val: &#inner_type) -> core::result::Result<(), #error_type_path> {
+            #const_fn fn __validate__(val: &#inner_type) -> core::result::Result<(), #returned_error_type_path> {
                 let val = *val;
-                #validations
-                Ok(())
+                #validation_result
             }
         )
     }
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        error_variants: ErrorVariants,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, error_type_path, validators)
+        gen_validation_error_type(
+            type_name,
+            inner_type,
+            error_type_path,
+            validators,
+            error_variants,
+        )
+    }
+
+    // Must be consistent with the derives in `error::gen_validation_error_type()`.
+    fn is_validation_error_eq(error_variants: ErrorVariants) -> bool {
+        error_variants == ErrorVariants::Unit
     }
 
     fn gen_traits(
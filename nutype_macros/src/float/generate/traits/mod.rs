@@ -222,7 +222,7 @@ fn gen_implemented_traits<T: ToTokens>(
             FloatIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
             FloatIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
             FloatIrregularTrait::FromStr => {
-                Ok(gen_impl_trait_from_str(type_name, generics, inner_type, maybe_error_type_name))
+                Ok(gen_impl_trait_from_str(type_name, generics, inner_type, guard.maybe_validation()))
             }
             FloatIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, inner_type)),
             FloatIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type)),
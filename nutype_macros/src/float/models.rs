@@ -79,7 +79,7 @@ impl TypeTrait for FloatDeriveTrait {
 pub type FloatRawGuard<T> = RawGuard<SpannedFloatSanitizer<T>, SpannedFloatValidator<T>>;
 pub type FloatGuard<T> = Guard<FloatSanitizer<T>, FloatValidator<T>>;
 
-pub trait FloatType {}
+pub trait FloatType: core::fmt::Debug {}
 
 macro_rules! define_float_inner_type {
     ($($tp:ty => $variant:ident),*) => {
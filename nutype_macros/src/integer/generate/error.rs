@@ -1,20 +1,35 @@
+use core::fmt::Debug;
+
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
 use super::super::models::IntegerValidator;
 use crate::common::{
-    generate::error::gen_impl_error_trait,
-    models::{ErrorTypePath, TypeName},
+    generate::{
+        doc::gen_error_variant_doc,
+        error::{
+            ViolatedRule, gen_error_variant, gen_impl_error_trait, gen_impl_validation_error_trait,
+            gen_rule,
+        },
+    },
+    models::{ErrorTypePath, ErrorVariants, TypeName},
 };
 
-pub fn gen_validation_error_type<T: ToTokens>(
+pub fn gen_validation_error_type<T: ToTokens + Debug>(
     type_name: &TypeName,
+    inner_type: impl ToTokens,
     error_type_path: &ErrorTypePath,
     validators: &[IntegerValidator<T>],
+    error_variants: ErrorVariants,
 ) -> TokenStream {
-    let definition = gen_definition(error_type_path, validators);
+    let definition = gen_definition(inner_type, error_type_path, validators, error_variants);
     let impl_display_trait = gen_impl_display_trait(type_name, error_type_path, validators);
     let impl_error_trait = gen_impl_error_trait(error_type_path);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        error_type_path,
+        &gen_violated_rules(error_type_path, validators),
+    );
 
     quote! {
         #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,38 +37,68 @@ pub fn gen_validation_error_type<T: ToTokens>(
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
-fn gen_definition<T>(
+fn gen_definition<T: Debug>(
+    inner_type: impl ToTokens,
     error_type_path: &ErrorTypePath,
     validators: &[IntegerValidator<T>],
+    error_variants: ErrorVariants,
 ) -> TokenStream {
-    let error_variants: TokenStream = validators
+    let variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            IntegerValidator::Greater(_) => {
-                quote!(GreaterViolated,)
-            }
-            IntegerValidator::GreaterOrEqual(_) => {
-                quote!(GreaterOrEqualViolated,)
-            }
-            IntegerValidator::Less(_) => {
-                quote!(LessViolated,)
-            }
-            IntegerValidator::LessOrEqual(_) => {
-                quote!(LessOrEqualViolated,)
-            }
-            IntegerValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
+        .map(|validator| {
+            let doc = gen_error_variant_doc(&gen_validator_rule(validator));
+            let variant = match validator {
+                IntegerValidator::Greater(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(GreaterViolated),
+                        quote!(min: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                IntegerValidator::GreaterOrEqual(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(GreaterOrEqualViolated),
+                        quote!(min: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                IntegerValidator::Less(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LessViolated),
+                        quote!(max: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                IntegerValidator::LessOrEqual(_) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LessOrEqualViolated),
+                        quote!(max: #inner_type, actual: #inner_type),
+                    );
+                    quote!(#variant,)
+                }
+                IntegerValidator::Luhn => {
+                    quote!(LuhnViolated,)
+                }
+                IntegerValidator::Predicate(_) => {
+                    quote!(PredicateViolated,)
+                }
+            };
+            quote!(#doc #variant)
         })
         .collect();
 
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_path {
-            #error_variants
+            #variants
         }
     }
 }
@@ -65,16 +110,19 @@ fn gen_impl_display_trait<T: ToTokens>(
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
         IntegerValidator::Greater(val) => quote! {
-             #error_type_path::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::GreaterViolated { .. } => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
         },
         IntegerValidator::GreaterOrEqual(val) => quote! {
-             #error_type_path::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::GreaterOrEqualViolated { .. } => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
         },
         IntegerValidator::Less(val) => quote! {
-             #error_type_path::LessViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::LessViolated { .. } => write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
         },
         IntegerValidator::LessOrEqual(val) => quote! {
-             #error_type_path::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+             #error_type_path::LessOrEqualViolated { .. } => write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+        },
+        IntegerValidator::Luhn => quote! {
+             #error_type_path::LuhnViolated => write!(f, "{} does not have a valid Luhn check digit.", stringify!(#type_name))
         },
         IntegerValidator::Predicate(_) => quote! {
              #error_type_path::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
@@ -91,3 +139,43 @@ fn gen_impl_display_trait<T: ToTokens>(
         }
     }
 }
+
+fn gen_violated_rules<T: Debug>(
+    error_type_path: &ErrorTypePath,
+    validators: &[IntegerValidator<T>],
+) -> Vec<ViolatedRule> {
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = match validator {
+                IntegerValidator::Greater(_) => quote!(#error_type_path::GreaterViolated { .. }),
+                IntegerValidator::GreaterOrEqual(_) => {
+                    quote!(#error_type_path::GreaterOrEqualViolated { .. })
+                }
+                IntegerValidator::Less(_) => quote!(#error_type_path::LessViolated { .. }),
+                IntegerValidator::LessOrEqual(_) => {
+                    quote!(#error_type_path::LessOrEqualViolated { .. })
+                }
+                IntegerValidator::Luhn => quote!(#error_type_path::LuhnViolated),
+                IntegerValidator::Predicate(_) => quote!(#error_type_path::PredicateViolated),
+            };
+            ViolatedRule {
+                pattern,
+                code: validator.kind().to_string(),
+                rule: gen_validator_rule(validator),
+            }
+        })
+        .collect()
+}
+
+/// Describes the validator as it's declared, e.g. `greater = 0`.
+pub fn gen_validator_rule<T: Debug>(validator: &IntegerValidator<T>) -> TokenStream {
+    let code = validator.kind().to_string();
+    match validator {
+        IntegerValidator::Greater(val)
+        | IntegerValidator::GreaterOrEqual(val)
+        | IntegerValidator::Less(val)
+        | IntegerValidator::LessOrEqual(val) => gen_rule(&code, val),
+        IntegerValidator::Luhn | IntegerValidator::Predicate(_) => quote!(#code),
+    }
+}
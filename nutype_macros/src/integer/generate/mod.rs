@@ -18,6 +18,9 @@ use super::{
 use crate::common::{
     generate::{
         GenerateNewtype,
+        constraints::{gen_bound_constraint_value, gen_validator_constraint},
+        error::gen_error_variant,
+        report::{gen_report_violation, gen_validation_result},
         tests::{
             gen_test_should_have_consistent_lower_and_upper_boundaries,
             gen_test_should_have_valid_default_value,
@@ -25,7 +28,8 @@ use crate::common::{
         traits::GeneratedTraits,
     },
     models::{
-        ConditionalDeriveGroup, ConstFn, ErrorTypePath, Guard, SpannedDeriveUnsafeTrait, TypeName,
+        ConditionalDeriveGroup, ConstFn, ErrorTypePath, ErrorVariants, Guard,
+        SpannedDeriveUnsafeTrait, TypeName, ValidationReport,
     },
 };
 
@@ -65,68 +69,156 @@ where
         )
     }
 
+    fn sanitizer_kind(sanitizer: &Self::Sanitizer) -> String {
+        match sanitizer {
+            IntegerSanitizer::With(_) => "with".to_string(),
+            IntegerSanitizer::_Phantom(_) => {
+                unreachable!("integer::gen: IntegerSanitizer::_Phantom must not be used")
+            }
+        }
+    }
+
+    fn gen_validator_rule(validator: &Self::Validator) -> TokenStream {
+        error::gen_validator_rule(validator)
+    }
+
+    fn gen_validator_constraint(
+        inner_type: &Self::InnerType,
+        validator: &Self::Validator,
+    ) -> TokenStream {
+        let kind = validator.kind().to_string();
+        let (variant, target_type) = if inner_type.is_signed() {
+            (quote!(Signed), quote!(i128))
+        } else {
+            (quote!(Unsigned), quote!(u128))
+        };
+        let maybe_value = match validator {
+            IntegerValidator::Greater(bound)
+            | IntegerValidator::GreaterOrEqual(bound)
+            | IntegerValidator::Less(bound)
+            | IntegerValidator::LessOrEqual(bound) => {
+                Some(gen_bound_constraint_value(bound, variant, target_type))
+            }
+            IntegerValidator::Luhn | IntegerValidator::Predicate(_) => None,
+        };
+        gen_validator_constraint(&kind, maybe_value)
+    }
+
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        report: &ValidationReport,
+        error_variants: ErrorVariants,
         const_fn: ConstFn,
     ) -> TokenStream {
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
                 IntegerValidator::Less(exclusive_upper_bound) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LessViolated),
+                        quote!(max: #exclusive_upper_bound, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val >= #exclusive_upper_bound {
-                            return Err(#error_type_path::LessViolated);
+                            #violation
                         }
                     )
                 }
                 IntegerValidator::LessOrEqual(max) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LessOrEqualViolated),
+                        quote!(max: #max, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val > #max {
-                            return Err(#error_type_path::LessOrEqualViolated);
+                            #violation
                         }
                     )
                 }
                 IntegerValidator::Greater(exclusive_lower_bound) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::GreaterViolated),
+                        quote!(min: #exclusive_lower_bound, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val <= #exclusive_lower_bound {
-                            return Err(#error_type_path::GreaterViolated);
+                            #violation
                         }
                     )
                 }
                 IntegerValidator::GreaterOrEqual(min) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::GreaterOrEqualViolated),
+                        quote!(min: #min, actual: val),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if val < #min {
-                            return Err(#error_type_path::GreaterOrEqualViolated);
+                            #violation
+                        }
+                    )
+                }
+                IntegerValidator::Luhn => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::LuhnViolated));
+                    // Negative numbers have no check digit.
+                    let maybe_non_negative = if inner_type.is_signed() {
+                        quote!(val < 0 ||)
+                    } else {
+                        quote!()
+                    };
+                    quote!(
+                        if #maybe_non_negative !::nutype::checksums::is_luhn_number(val as u128) {
+                            #violation
                         }
                     )
                 }
                 IntegerValidator::Predicate(custom_is_valid_fn) => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::PredicateViolated));
                     quote!(
                         if !(#custom_is_valid_fn)(&val) {
-                            return Err(#error_type_path::PredicateViolated);
+                            #violation
                         }
                     )
                 }
             })
             .collect();
 
+        let validation_result = gen_validation_result(report, validations);
+        let returned_error_type_path = report.returned_error_type_path(error_type_path);
+
         quote!(
-            #const_fn fn __validate__(val: &#inner_type) -> ::core::result::Result<(), #error_type_path> {
+            #const_fn fn __validate__(val: &#inner_type) -> ::core::result::Result<(), #returned_error_type_path> {
                 let val = *val;
-                #validations
-                Ok(())
+                #validation_result
             }
         )
     }
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        error_variants: ErrorVariants,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, error_type_path, validators)
+        gen_validation_error_type(
+            type_name,
+            inner_type,
+            error_type_path,
+            validators,
+            error_variants,
+        )
     }
 
     fn gen_traits(
@@ -78,6 +78,8 @@ fn guard_to_boundary<T: ToTokens>(
                 Validation::Standard {
                     validators,
                     error_type_path: _,
+                    report: _,
+                    error_variants: _,
                 } => {
                     // Apply the validators to the boundaries.
                     // Since the validators were already validated, it's guaranteed that they're not
@@ -96,6 +98,12 @@ fn guard_to_boundary<T: ToTokens>(
                             IntegerValidator::LessOrEqual(lte) => {
                                 boundary.max = quote!(#lte);
                             }
+                            IntegerValidator::Luhn => {
+                                return Err(syn::Error::new(
+                                    proc_macro2::Span::call_site(),
+                                    "Cannot derive trait `Arbitrary` for a type with `luhn` validator",
+                                ));
+                            }
                             IntegerValidator::Predicate(_) => {
                                 return Err(syn::Error::new(
                                     proc_macro2::Span::call_site(),
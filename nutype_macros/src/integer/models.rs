@@ -28,6 +28,7 @@ pub enum IntegerValidator<T> {
     GreaterOrEqual(ValueOrExpr<T>),
     Less(ValueOrExpr<T>),
     LessOrEqual(ValueOrExpr<T>),
+    Luhn,
     Predicate(TypedCustomFunction),
 }
 
@@ -79,7 +80,7 @@ impl TypeTrait for IntegerDeriveTrait {
 pub type IntegerRawGuard<T> = RawGuard<SpannedIntegerSanitizer<T>, SpannedIntegerValidator<T>>;
 pub type IntegerGuard<T> = Guard<IntegerSanitizer<T>, IntegerValidator<T>>;
 
-pub trait IntegerType {}
+pub trait IntegerType: core::fmt::Debug {}
 
 macro_rules! define_integer_inner_type {
     ($($tp:ty => $variant:ident),*) => {
@@ -130,3 +131,12 @@ define_integer_inner_type!(
     i128 => I128,
     isize => Isize
 );
+
+impl IntegerInnerType {
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::Isize
+        )
+    }
+}
@@ -69,7 +69,7 @@ where
     <T as FromStr>::Err: Display,
 {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_validator_kind(input)?;
+        let (kind, ident) = parse_validator_kind(input)?;
 
         match kind {
             IntegerValidatorKind::Greater => {
@@ -104,6 +104,10 @@ where
                     span,
                 })
             }
+            IntegerValidatorKind::Luhn => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::Luhn,
+                span: ident.span(),
+            }),
             IntegerValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) = parse_typed_custom_function::<&T>(input)?;
@@ -3,20 +3,32 @@ use quote::quote;
 
 use crate::{
     common::{
-        generate::error::gen_impl_error_trait,
-        models::{ErrorTypePath, TypeName},
+        generate::{
+            doc::gen_error_variant_doc,
+            error::{
+                ViolatedRule, gen_error_variant, gen_impl_error_trait,
+                gen_impl_validation_error_trait, gen_rule, gen_rule_from_tokens,
+            },
+        },
+        models::{ErrorTypePath, ErrorVariants, TypeName},
     },
-    string::models::StringValidator,
+    string::models::{RegexDef, StringValidator},
 };
 
 pub fn gen_validation_error_type(
     type_name: &TypeName,
     error_type_path: &ErrorTypePath,
     validators: &[StringValidator],
+    error_variants: ErrorVariants,
 ) -> TokenStream {
-    let definition = gen_definition(error_type_path, validators);
+    let definition = gen_definition(error_type_path, validators, error_variants);
     let impl_display_trait = gen_impl_display_trait(type_name, error_type_path, validators);
     let impl_error_trait = gen_impl_error_trait(error_type_path);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        error_type_path,
+        &gen_violated_rules(error_type_path, validators),
+    );
 
     quote! {
         #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,41 +36,187 @@ pub fn gen_validation_error_type(
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
-fn gen_definition(error_type_path: &ErrorTypePath, validators: &[StringValidator]) -> TokenStream {
-    let error_variants: TokenStream = validators
+fn gen_definition(
+    error_type_path: &ErrorTypePath,
+    validators: &[StringValidator],
+    error_variants: ErrorVariants,
+) -> TokenStream {
+    let variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            StringValidator::LenCharMax(_len) => {
-                quote!(LenCharMaxViolated,)
-            }
-            StringValidator::LenCharMin(_len) => {
-                quote!(LenCharMinViolated,)
-            }
-            StringValidator::LenUtf16Max(_len) => {
-                quote!(LenUtf16MaxViolated,)
-            }
-            StringValidator::LenUtf16Min(_len) => {
-                quote!(LenUtf16MinViolated,)
-            }
-            StringValidator::NotEmpty => {
-                quote!(NotEmptyViolated,)
-            }
-            StringValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
-            StringValidator::Regex(_) => {
-                quote!(RegexViolated,)
-            }
+        .map(|validator| {
+            let doc = gen_error_variant_doc(&gen_validator_rule(validator));
+            let variant = match validator {
+                StringValidator::LenCharMax(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenCharMaxViolated),
+                        quote!(max: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::LenCharMin(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenCharMinViolated),
+                        quote!(min: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::LenUtf16Max(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenUtf16MaxViolated),
+                        quote!(max: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::LenUtf16Min(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenUtf16MinViolated),
+                        quote!(min: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::LenBytesMax(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenBytesMaxViolated),
+                        quote!(max: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::LenBytesMin(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenBytesMinViolated),
+                        quote!(min: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::LenGraphemeMax(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenGraphemeMaxViolated),
+                        quote!(max: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::LenGraphemeMin(_len) => {
+                    let variant = gen_error_variant(
+                        error_variants,
+                        quote!(LenGraphemeMinViolated),
+                        quote!(min: usize, actual: usize),
+                    );
+                    quote!(#variant,)
+                }
+                StringValidator::NotEmpty => {
+                    quote!(NotEmptyViolated,)
+                }
+                StringValidator::Ascii => {
+                    quote!(AsciiViolated,)
+                }
+                StringValidator::Alphanumeric => {
+                    quote!(AlphanumericViolated,)
+                }
+                StringValidator::Printable => {
+                    quote!(PrintableViolated,)
+                }
+                StringValidator::NoControlChars => {
+                    quote!(NoControlCharsViolated,)
+                }
+                StringValidator::StartsWith(_) => {
+                    quote!(StartsWithViolated,)
+                }
+                StringValidator::EndsWith(_) => {
+                    quote!(EndsWithViolated,)
+                }
+                StringValidator::Contains(_) => {
+                    quote!(ContainsViolated,)
+                }
+                StringValidator::NotContains(_) => {
+                    quote!(NotContainsViolated,)
+                }
+                StringValidator::OneOf(_) => {
+                    quote!(OneOfViolated,)
+                }
+                StringValidator::Slug { .. } => {
+                    quote!(SlugViolated,)
+                }
+                StringValidator::Identifier => {
+                    quote!(IdentifierViolated,)
+                }
+                StringValidator::Email => {
+                    quote!(EmailViolated,)
+                }
+                StringValidator::Url => {
+                    quote!(UrlViolated,)
+                }
+                StringValidator::Uuid => {
+                    quote!(UuidViolated,)
+                }
+                StringValidator::Ipv4 => {
+                    quote!(Ipv4Violated,)
+                }
+                StringValidator::Ipv6 => {
+                    quote!(Ipv6Violated,)
+                }
+                StringValidator::Hex => {
+                    quote!(HexViolated,)
+                }
+                StringValidator::Base64 => {
+                    quote!(Base64Violated,)
+                }
+                StringValidator::Semver => {
+                    quote!(SemverViolated,)
+                }
+                StringValidator::Iso8601Date => {
+                    quote!(Iso8601DateViolated,)
+                }
+                StringValidator::Luhn => {
+                    quote!(LuhnViolated,)
+                }
+                StringValidator::Iban => {
+                    quote!(IbanViolated,)
+                }
+                StringValidator::Isbn => {
+                    quote!(IsbnViolated,)
+                }
+                StringValidator::Ean => {
+                    quote!(EanViolated,)
+                }
+                StringValidator::Iso3166Alpha2 => {
+                    quote!(Iso3166Alpha2Violated,)
+                }
+                StringValidator::Iso3166Alpha3 => {
+                    quote!(Iso3166Alpha3Violated,)
+                }
+                StringValidator::Iso4217 => {
+                    quote!(Iso4217Violated,)
+                }
+                StringValidator::Bcp47 => {
+                    quote!(Bcp47Violated,)
+                }
+                StringValidator::Predicate(_) => {
+                    quote!(PredicateViolated,)
+                }
+                StringValidator::Regex(_) | StringValidator::NotRegex(_) => {
+                    let variant = validator.regex_error_variant();
+                    quote!(#variant,)
+                }
+            };
+            quote!(#doc #variant)
         })
         .collect();
 
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_path {
-            #error_variants
+            #variants
         }
     }
 }
@@ -70,7 +228,7 @@ fn gen_impl_display_trait(
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
         StringValidator::LenCharMax(len_char_max) => quote! {
-            #error_type_path::LenCharMaxViolated => write!(
+            #error_type_path::LenCharMaxViolated { .. } => write!(
                 f,
                 "{} is too long: the maximum valid length is {} character{}.",
                 stringify!(#type_name),
@@ -79,7 +237,7 @@ fn gen_impl_display_trait(
             )
         },
         StringValidator::LenCharMin(len_char_min) => quote! {
-            #error_type_path::LenCharMinViolated => write!(
+            #error_type_path::LenCharMinViolated { .. } => write!(
                 f,
                 "{} is too short: the minimum valid length is {} character{}.",
                 stringify!(#type_name),
@@ -88,7 +246,7 @@ fn gen_impl_display_trait(
             )
         },
         StringValidator::LenUtf16Max(len_utf16_max) => quote! {
-            #error_type_path::LenUtf16MaxViolated => write!(
+            #error_type_path::LenUtf16MaxViolated { .. } => write!(
                 f,
                 "{} is too long: the maximum valid UTF-16 length is {} code unit{}.",
                 stringify!(#type_name),
@@ -97,7 +255,7 @@ fn gen_impl_display_trait(
             )
         },
         StringValidator::LenUtf16Min(len_utf16_min) => quote! {
-            #error_type_path::LenUtf16MinViolated => write!(
+            #error_type_path::LenUtf16MinViolated { .. } => write!(
                 f,
                 "{} is too short: the minimum valid UTF-16 length is {} code unit{}.",
                 stringify!(#type_name),
@@ -105,15 +263,151 @@ fn gen_impl_display_trait(
                 if #len_utf16_min == 1 { "" } else { "s" }
             )
         },
+        StringValidator::LenBytesMax(len_bytes_max) => quote! {
+            #error_type_path::LenBytesMaxViolated { .. } => write!(
+                f,
+                "{} is too long: the maximum valid length is {} byte{}.",
+                stringify!(#type_name),
+                #len_bytes_max,
+                if #len_bytes_max == 1 { "" } else { "s" }
+            )
+        },
+        StringValidator::LenBytesMin(len_bytes_min) => quote! {
+            #error_type_path::LenBytesMinViolated { .. } => write!(
+                f,
+                "{} is too short: the minimum valid length is {} byte{}.",
+                stringify!(#type_name),
+                #len_bytes_min,
+                if #len_bytes_min == 1 { "" } else { "s" }
+            )
+        },
+        StringValidator::LenGraphemeMax(len_grapheme_max) => quote! {
+            #error_type_path::LenGraphemeMaxViolated { .. } => write!(
+                f,
+                "{} is too long: the maximum valid length is {} grapheme cluster{}.",
+                stringify!(#type_name),
+                #len_grapheme_max,
+                if #len_grapheme_max == 1 { "" } else { "s" }
+            )
+        },
+        StringValidator::LenGraphemeMin(len_grapheme_min) => quote! {
+            #error_type_path::LenGraphemeMinViolated { .. } => write!(
+                f,
+                "{} is too short: the minimum valid length is {} grapheme cluster{}.",
+                stringify!(#type_name),
+                #len_grapheme_min,
+                if #len_grapheme_min == 1 { "" } else { "s" }
+            )
+        },
         StringValidator::NotEmpty => quote! {
              #error_type_path::NotEmptyViolated => write!(f, "{} is empty.", stringify!(#type_name))
         },
+        StringValidator::Ascii => quote! {
+             #error_type_path::AsciiViolated => write!(f, "{} contains non-ASCII characters.", stringify!(#type_name))
+        },
+        StringValidator::Alphanumeric => quote! {
+             #error_type_path::AlphanumericViolated => write!(f, "{} contains characters that are neither letters nor digits.", stringify!(#type_name))
+        },
+        StringValidator::Printable => quote! {
+             #error_type_path::PrintableViolated => write!(f, "{} contains non-printable characters.", stringify!(#type_name))
+        },
+        StringValidator::NoControlChars => quote! {
+             #error_type_path::NoControlCharsViolated => write!(f, "{} contains control characters.", stringify!(#type_name))
+        },
+        StringValidator::StartsWith(prefix) => quote! {
+             #error_type_path::StartsWithViolated => write!(f, "{} must start with {:?}.", stringify!(#type_name), #prefix)
+        },
+        StringValidator::EndsWith(suffix) => quote! {
+             #error_type_path::EndsWithViolated => write!(f, "{} must end with {:?}.", stringify!(#type_name), #suffix)
+        },
+        StringValidator::Contains(substring) => quote! {
+             #error_type_path::ContainsViolated => write!(f, "{} must contain {:?}.", stringify!(#type_name), #substring)
+        },
+        StringValidator::NotContains(substring) => quote! {
+             #error_type_path::NotContainsViolated => write!(f, "{} must not contain {:?}.", stringify!(#type_name), #substring)
+        },
+        StringValidator::Slug { separator } => quote! {
+             #error_type_path::SlugViolated => write!(f, "{} is not a valid slug: expected lowercase ASCII letters and digits separated by {:?}.", stringify!(#type_name), #separator)
+        },
+        StringValidator::Identifier => quote! {
+             #error_type_path::IdentifierViolated => write!(f, "{} is not a valid identifier: expected ASCII letters, digits and underscores, not starting with a digit.", stringify!(#type_name))
+        },
+        StringValidator::Email => quote! {
+             #error_type_path::EmailViolated => write!(f, "{} is not a valid email address.", stringify!(#type_name))
+        },
+        StringValidator::Url => quote! {
+             #error_type_path::UrlViolated => write!(f, "{} is not a valid URL.", stringify!(#type_name))
+        },
+        StringValidator::Uuid => quote! {
+             #error_type_path::UuidViolated => write!(f, "{} is not a valid UUID.", stringify!(#type_name))
+        },
+        StringValidator::Ipv4 => quote! {
+             #error_type_path::Ipv4Violated => write!(f, "{} is not a valid IPv4 address.", stringify!(#type_name))
+        },
+        StringValidator::Ipv6 => quote! {
+             #error_type_path::Ipv6Violated => write!(f, "{} is not a valid IPv6 address.", stringify!(#type_name))
+        },
+        StringValidator::Hex => quote! {
+             #error_type_path::HexViolated => write!(f, "{} is not a valid hexadecimal string.", stringify!(#type_name))
+        },
+        StringValidator::Base64 => quote! {
+             #error_type_path::Base64Violated => write!(f, "{} is not a valid Base64 string.", stringify!(#type_name))
+        },
+        StringValidator::Semver => quote! {
+             #error_type_path::SemverViolated => write!(f, "{} is not a valid semantic version.", stringify!(#type_name))
+        },
+        StringValidator::Iso8601Date => quote! {
+             #error_type_path::Iso8601DateViolated => write!(f, "{} is not a valid ISO 8601 date (YYYY-MM-DD).", stringify!(#type_name))
+        },
+        StringValidator::Luhn => quote! {
+             #error_type_path::LuhnViolated => write!(f, "{} is not a sequence of digits with a valid Luhn check digit.", stringify!(#type_name))
+        },
+        StringValidator::Iban => quote! {
+             #error_type_path::IbanViolated => write!(f, "{} is not a valid IBAN.", stringify!(#type_name))
+        },
+        StringValidator::Isbn => quote! {
+             #error_type_path::IsbnViolated => write!(f, "{} is not a valid ISBN.", stringify!(#type_name))
+        },
+        StringValidator::Ean => quote! {
+             #error_type_path::EanViolated => write!(f, "{} is not a valid EAN.", stringify!(#type_name))
+        },
+        StringValidator::Iso3166Alpha2 => quote! {
+             #error_type_path::Iso3166Alpha2Violated => write!(f, "{} is not a valid ISO 3166-1 alpha-2 country code.", stringify!(#type_name))
+        },
+        StringValidator::Iso3166Alpha3 => quote! {
+             #error_type_path::Iso3166Alpha3Violated => write!(f, "{} is not a valid ISO 3166-1 alpha-3 country code.", stringify!(#type_name))
+        },
+        StringValidator::Iso4217 => quote! {
+             #error_type_path::Iso4217Violated => write!(f, "{} is not a valid ISO 4217 currency code.", stringify!(#type_name))
+        },
+        StringValidator::Bcp47 => quote! {
+             #error_type_path::Bcp47Violated => write!(f, "{} is not a valid BCP 47 language tag.", stringify!(#type_name))
+        },
+        StringValidator::OneOf(values) => {
+            let allowed_values = format_str_list(values);
+            quote! {
+                #error_type_path::OneOfViolated => write!(f, "{} must be one of: {}.", stringify!(#type_name), #allowed_values)
+            }
+        }
         StringValidator::Predicate(_) => quote! {
              #error_type_path::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
-        StringValidator::Regex(_) => quote! {
-             #error_type_path::RegexViolated => write!(f, "{} violated the regular expression.", stringify!(#type_name))
-        },
+        StringValidator::Regex(rule) | StringValidator::NotRegex(rule) => {
+            let variant = validator.regex_error_variant();
+            let description = match (validator, &rule.variant) {
+                (StringValidator::Regex(_), None) => "violated the regular expression".to_string(),
+                (StringValidator::Regex(_), Some(name)) => {
+                    format!("violated the regular expression of `{name}` rule")
+                }
+                (_, None) => "matches a forbidden regular expression".to_string(),
+                (_, Some(name)) => {
+                    format!("matches a forbidden regular expression of `{name}` rule")
+                }
+            };
+            quote! {
+                #error_type_path::#variant => write!(f, "{} {}.", stringify!(#type_name), #description)
+            }
+        }
     });
 
     quote! {
@@ -126,3 +420,150 @@ fn gen_impl_display_trait(
         }
     }
 }
+
+fn gen_violated_rules(
+    error_type_path: &ErrorTypePath,
+    validators: &[StringValidator],
+) -> Vec<ViolatedRule> {
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = match validator {
+                StringValidator::LenCharMax(_) => {
+                    quote!(#error_type_path::LenCharMaxViolated { .. })
+                }
+                StringValidator::LenCharMin(_) => {
+                    quote!(#error_type_path::LenCharMinViolated { .. })
+                }
+                StringValidator::LenUtf16Max(_) => {
+                    quote!(#error_type_path::LenUtf16MaxViolated { .. })
+                }
+                StringValidator::LenUtf16Min(_) => {
+                    quote!(#error_type_path::LenUtf16MinViolated { .. })
+                }
+                StringValidator::LenBytesMax(_) => {
+                    quote!(#error_type_path::LenBytesMaxViolated { .. })
+                }
+                StringValidator::LenBytesMin(_) => {
+                    quote!(#error_type_path::LenBytesMinViolated { .. })
+                }
+                StringValidator::LenGraphemeMax(_) => {
+                    quote!(#error_type_path::LenGraphemeMaxViolated { .. })
+                }
+                StringValidator::LenGraphemeMin(_) => {
+                    quote!(#error_type_path::LenGraphemeMinViolated { .. })
+                }
+                StringValidator::NotEmpty => quote!(#error_type_path::NotEmptyViolated),
+                StringValidator::Ascii => quote!(#error_type_path::AsciiViolated),
+                StringValidator::Alphanumeric => quote!(#error_type_path::AlphanumericViolated),
+                StringValidator::Printable => quote!(#error_type_path::PrintableViolated),
+                StringValidator::NoControlChars => quote!(#error_type_path::NoControlCharsViolated),
+                StringValidator::StartsWith(_) => quote!(#error_type_path::StartsWithViolated),
+                StringValidator::EndsWith(_) => quote!(#error_type_path::EndsWithViolated),
+                StringValidator::Contains(_) => quote!(#error_type_path::ContainsViolated),
+                StringValidator::NotContains(_) => quote!(#error_type_path::NotContainsViolated),
+                StringValidator::OneOf(_) => quote!(#error_type_path::OneOfViolated),
+                StringValidator::Slug { .. } => quote!(#error_type_path::SlugViolated),
+                StringValidator::Identifier => quote!(#error_type_path::IdentifierViolated),
+                StringValidator::Email => quote!(#error_type_path::EmailViolated),
+                StringValidator::Url => quote!(#error_type_path::UrlViolated),
+                StringValidator::Uuid => quote!(#error_type_path::UuidViolated),
+                StringValidator::Ipv4 => quote!(#error_type_path::Ipv4Violated),
+                StringValidator::Ipv6 => quote!(#error_type_path::Ipv6Violated),
+                StringValidator::Hex => quote!(#error_type_path::HexViolated),
+                StringValidator::Base64 => quote!(#error_type_path::Base64Violated),
+                StringValidator::Semver => quote!(#error_type_path::SemverViolated),
+                StringValidator::Iso8601Date => quote!(#error_type_path::Iso8601DateViolated),
+                StringValidator::Luhn => quote!(#error_type_path::LuhnViolated),
+                StringValidator::Iban => quote!(#error_type_path::IbanViolated),
+                StringValidator::Isbn => quote!(#error_type_path::IsbnViolated),
+                StringValidator::Ean => quote!(#error_type_path::EanViolated),
+                StringValidator::Iso3166Alpha2 => quote!(#error_type_path::Iso3166Alpha2Violated),
+                StringValidator::Iso3166Alpha3 => quote!(#error_type_path::Iso3166Alpha3Violated),
+                StringValidator::Iso4217 => quote!(#error_type_path::Iso4217Violated),
+                StringValidator::Bcp47 => quote!(#error_type_path::Bcp47Violated),
+                StringValidator::Predicate(_) => quote!(#error_type_path::PredicateViolated),
+                StringValidator::Regex(_) | StringValidator::NotRegex(_) => {
+                    let variant = validator.regex_error_variant();
+                    quote!(#error_type_path::#variant)
+                }
+            };
+            ViolatedRule {
+                pattern,
+                code: validator.kind().to_string(),
+                rule: gen_validator_rule(validator),
+            }
+        })
+        .collect()
+}
+
+/// Describes the validator as it's declared, e.g. `len_char_max = 20`.
+pub fn gen_validator_rule(validator: &StringValidator) -> TokenStream {
+    let code = validator.kind().to_string();
+    match validator {
+        StringValidator::LenCharMax(len)
+        | StringValidator::LenCharMin(len)
+        | StringValidator::LenUtf16Max(len)
+        | StringValidator::LenUtf16Min(len)
+        | StringValidator::LenBytesMax(len)
+        | StringValidator::LenBytesMin(len)
+        | StringValidator::LenGraphemeMax(len)
+        | StringValidator::LenGraphemeMin(len) => gen_rule(&code, len),
+        StringValidator::NotEmpty
+        | StringValidator::Email
+        | StringValidator::Url
+        | StringValidator::Uuid
+        | StringValidator::Ipv4
+        | StringValidator::Ipv6
+        | StringValidator::Hex
+        | StringValidator::Base64
+        | StringValidator::Semver
+        | StringValidator::Iso8601Date
+        | StringValidator::Luhn
+        | StringValidator::Iban
+        | StringValidator::Isbn
+        | StringValidator::Ean
+        | StringValidator::Iso3166Alpha2
+        | StringValidator::Iso3166Alpha3
+        | StringValidator::Iso4217
+        | StringValidator::Bcp47
+        | StringValidator::Ascii
+        | StringValidator::Alphanumeric
+        | StringValidator::Printable
+        | StringValidator::NoControlChars
+        | StringValidator::Identifier
+        | StringValidator::Slug { separator: '-' }
+        | StringValidator::Predicate(_) => quote!(#code),
+        StringValidator::Slug { separator } => {
+            let rule = format!("{code}(separator = {separator:?})");
+            quote!(#rule)
+        }
+        StringValidator::StartsWith(lit)
+        | StringValidator::EndsWith(lit)
+        | StringValidator::Contains(lit)
+        | StringValidator::NotContains(lit) => {
+            let rule = format!("{code} = {:?}", lit.value());
+            quote!(#rule)
+        }
+        StringValidator::OneOf(values) => {
+            let rule = format!("{code} = [{}]", format_str_list(values));
+            quote!(#rule)
+        }
+        StringValidator::Regex(rule) | StringValidator::NotRegex(rule) => match &rule.def {
+            RegexDef::StringLiteral(lit) => {
+                let rule = format!("{code} = {:?}", lit.value());
+                quote!(#rule)
+            }
+            RegexDef::Path(path) => gen_rule_from_tokens(&code, path),
+        },
+    }
+}
+
+/// Formats the values as a list of quoted strings, e.g. `"EUR", "USD"`.
+fn format_str_list(values: &[syn::LitStr]) -> String {
+    values
+        .iter()
+        .map(|value| format!("{:?}", value.value()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -1,25 +1,34 @@
 pub mod error;
+pub mod sanitize;
 pub mod tests;
 pub mod traits;
 
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::Generics;
 
 use crate::{
     common::{
         generate::{
-            GenerateNewtype, tests::gen_test_should_have_valid_default_value,
+            GenerateNewtype,
+            constraints::{
+                gen_bound_constraint_value, gen_expr_constraint_value, gen_validator_constraint,
+            },
+            error::gen_error_variant,
+            report::{gen_report_violation, gen_validation_result},
+            tests::gen_test_should_have_valid_default_value,
             traits::GeneratedTraits,
         },
         models::{
-            ConditionalDeriveGroup, ConstFn, ErrorTypePath, Guard, SpannedDeriveUnsafeTrait,
-            TypeName,
+            ConditionalDeriveGroup, ConstFn, ConstructorVisibility, ErrorTypePath, ErrorVariants,
+            Guard, SpannedDeriveUnsafeTrait, TypeName, Validation, ValidationReport, ValueOrExpr,
         },
     },
-    string::models::{RegexDef, StringInnerType, StringSanitizer, StringValidator},
+    string::models::{
+        RegexDef, StringInnerType, StringSanitizer, StringValidator, one_of_const_name,
+    },
 };
 
 use self::{error::gen_validation_error_type, traits::gen_traits};
@@ -47,46 +56,287 @@ impl GenerateNewtype for StringNewtype {
         sanitizers: &[Self::Sanitizer],
         const_fn: ConstFn,
     ) -> TokenStream {
-        let transformations: TokenStream = sanitizers
-            .iter()
-            .map(|san| match san {
-                StringSanitizer::Trim => {
-                    // TODO: consider optimizing sequences of [trim, lowercase] and [trim, uppercase] to avoid
-                    // unnecessary allocation with `to_string()`
-                    quote!(
-                        let value: String = value.trim().to_string();
-                    )
-                }
-                StringSanitizer::Lowercase => {
-                    quote!(
-                        let value: String = value.to_lowercase();
-                    )
-                }
-                StringSanitizer::Uppercase => {
-                    quote!(
-                        let value: String = value.to_uppercase();
-                    )
-                }
-                StringSanitizer::With(typed_custom_function) => {
-                    quote!(
-                        let value: String = (#typed_custom_function)(value);
-                    )
-                }
-            })
-            .collect();
+        let transformations = sanitize::gen_sanitizers(sanitizers);
+
+        let maybe_mut = if sanitizers.is_empty() {
+            quote!()
+        } else {
+            quote!(mut)
+        };
 
         quote!(
-            #const_fn fn __sanitize__(value: String) -> String {
+            #const_fn fn __sanitize__(#maybe_mut value: String) -> String {
                 #transformations
                 value
             }
         )
     }
 
+    fn sanitizer_kind(sanitizer: &Self::Sanitizer) -> String {
+        sanitizer.kind().to_string()
+    }
+
+    fn gen_validator_rule(validator: &Self::Validator) -> TokenStream {
+        error::gen_validator_rule(validator)
+    }
+
+    fn gen_validator_constraint(
+        _inner_type: &Self::InnerType,
+        validator: &Self::Validator,
+    ) -> TokenStream {
+        let kind = validator.kind().to_string();
+        let maybe_value = match validator {
+            StringValidator::LenCharMin(len)
+            | StringValidator::LenCharMax(len)
+            | StringValidator::LenUtf16Min(len)
+            | StringValidator::LenUtf16Max(len)
+            | StringValidator::LenBytesMin(len)
+            | StringValidator::LenBytesMax(len)
+            | StringValidator::LenGraphemeMin(len)
+            | StringValidator::LenGraphemeMax(len) => Some(gen_bound_constraint_value(
+                len,
+                quote!(Length),
+                quote!(usize),
+            )),
+            StringValidator::Regex(rule) | StringValidator::NotRegex(rule) => match &rule.def {
+                RegexDef::StringLiteral(regex_str_lit) => {
+                    Some(quote!(::nutype::ConstraintValue::Pattern(#regex_str_lit)))
+                }
+                RegexDef::Path(regex_path) => Some(gen_expr_constraint_value(regex_path)),
+            },
+            StringValidator::StartsWith(lit)
+            | StringValidator::EndsWith(lit)
+            | StringValidator::Contains(lit)
+            | StringValidator::NotContains(lit) => {
+                Some(quote!(::nutype::ConstraintValue::Text(#lit)))
+            }
+            StringValidator::OneOf(values) => {
+                Some(quote!(::nutype::ConstraintValue::TextList(&[#(#values),*])))
+            }
+            StringValidator::NotEmpty
+            | StringValidator::Email
+            | StringValidator::Url
+            | StringValidator::Uuid
+            | StringValidator::Ipv4
+            | StringValidator::Ipv6
+            | StringValidator::Hex
+            | StringValidator::Base64
+            | StringValidator::Semver
+            | StringValidator::Iso8601Date
+            | StringValidator::Luhn
+            | StringValidator::Iban
+            | StringValidator::Isbn
+            | StringValidator::Ean
+            | StringValidator::Iso3166Alpha2
+            | StringValidator::Iso3166Alpha3
+            | StringValidator::Iso4217
+            | StringValidator::Bcp47
+            | StringValidator::Ascii
+            | StringValidator::Alphanumeric
+            | StringValidator::Printable
+            | StringValidator::NoControlChars
+            | StringValidator::Slug { .. }
+            | StringValidator::Identifier
+            | StringValidator::Predicate(_) => None,
+        };
+        gen_validator_constraint(&kind, maybe_value)
+    }
+
+    fn gen_extra_constructors(
+        type_name: &TypeName,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+        constructor_visibility: &ConstructorVisibility,
+    ) -> TokenStream {
+        let Guard::WithValidation {
+            sanitizers,
+            validation,
+        } = guard
+        else {
+            return quote!();
+        };
+        let error_type_path = validation.error_type_path();
+
+        // The standard validation works on `&str`, so the input can be validated before
+        // allocation, unless the sanitizers need an owned `String` (all of them except
+        // `trim`, `trim_start` and `trim_end`, which take a subslice).
+        let maybe_slicing_sanitizers: Option<Vec<TokenStream>> = sanitizers
+            .iter()
+            .map(|sanitizer| match sanitizer {
+                StringSanitizer::Trim => Some(quote!(let raw_value = raw_value.trim();)),
+                StringSanitizer::TrimStart => Some(quote!(let raw_value = raw_value.trim_start();)),
+                StringSanitizer::TrimEnd => Some(quote!(let raw_value = raw_value.trim_end();)),
+                _ => None,
+            })
+            .collect();
+        let maybe_slicing_sanitizers =
+            maybe_slicing_sanitizers.filter(|_| matches!(validation, Validation::Standard { .. }));
+
+        let (doc, body) = if let Some(slicing_sanitizers) = maybe_slicing_sanitizers {
+            (
+                quote! {
+                    /// Creates a new value from a string slice.
+                    /// The input is validated before allocation, so invalid input is rejected
+                    /// without allocating a `String`.
+                },
+                quote! {
+                    #(#slicing_sanitizers)*
+                    Self::__validate__(raw_value)?;
+                    // `ToString` is not in the prelude of `no_std` crates.
+                    Ok(Self(::core::convert::Into::into(raw_value)))
+                },
+            )
+        } else {
+            (
+                quote! {
+                    /// Creates a new value from a string slice.
+                    /// The sanitizers of the type require an owned `String`, so it's equivalent to `try_new()`.
+                },
+                quote!(Self::try_new(raw_value)),
+            )
+        };
+
+        quote! {
+            impl #type_name {
+                #doc
+                #constructor_visibility fn try_from_str(raw_value: &str) -> ::core::result::Result<Self, #error_type_path> {
+                    #body
+                }
+            }
+        }
+    }
+
+    fn gen_extra_consts(
+        type_name: &TypeName,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        let Some(Validation::Standard { validators, .. }) = guard.maybe_validation() else {
+            return quote!();
+        };
+        let Some(values) = validators.iter().find_map(|validator| match validator {
+            StringValidator::OneOf(values) => Some(values),
+            _ => None,
+        }) else {
+            return quote!();
+        };
+
+        // The names are checked for uniqueness when `one_of` is validated.
+        // Values without ASCII letters or digits get no constant.
+        let consts = values.iter().filter_map(|value| {
+            let name = syn::Ident::new(&one_of_const_name(&value.value())?, value.span());
+            let doc = format!(" Allowed value `{:?}`.", value.value());
+            Some(quote! {
+                #[doc = #doc]
+                pub const #name: &'static str = #value;
+            })
+        });
+
+        quote! {
+            impl #type_name {
+                #(#consts)*
+            }
+        }
+    }
+
+    #[cfg(any(feature = "regex", feature = "regex_lite"))]
+    fn gen_extra_methods(
+        type_name: &TypeName,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+        vis: &TokenStream,
+    ) -> TokenStream {
+        use crate::string::{models::RegexRule, regex_captures::named_capture_groups};
+
+        let Some(Validation::Standard { validators, .. }) = guard.maybe_validation() else {
+            return quote!();
+        };
+
+        // The regex of a `regex` rule with `captures` flag is declared within the module, so
+        // the accessors share it with the validation. The names of the groups are checked,
+        // when the validators are validated.
+        let mut regexes = Vec::new();
+        let mut accessors = Vec::new();
+        for validator in validators {
+            let StringValidator::Regex(RegexRule {
+                def: RegexDef::StringLiteral(regex_str_lit),
+                captures: true,
+                ..
+            }) = validator
+            else {
+                continue;
+            };
+            let variant = validator
+                .regex_error_variant()
+                .expect("regex rule must have an error variant");
+            let regex_name = gen_regex_name(&variant);
+            regexes.push(gen_lazy_regex_static(&regex_name, regex_str_lit, false));
+            for group in named_capture_groups(&regex_str_lit.value()) {
+                let name = &group.name;
+                let method = group
+                    .accessor_ident(regex_str_lit.span())
+                    .expect("capture group names are validated");
+                let capture = quote!(#regex_name.capture_range(&self.0, #name));
+                let accessor = if group.is_optional {
+                    let doc = format!(
+                        " Returns `{name}` group captured by the regex `{}`, if the group participates in the match.",
+                        regex_str_lit.value()
+                    );
+                    quote! {
+                        #[doc = #doc]
+                        #vis fn #method(&self) -> ::core::option::Option<&str> {
+                            #capture.map(|range| &self.0[range])
+                        }
+                    }
+                } else {
+                    let doc = format!(
+                        " Returns `{name}` group captured by the regex `{}`.",
+                        regex_str_lit.value()
+                    );
+                    quote! {
+                        #[doc = #doc]
+                        ///
+                        /// # Panics
+                        ///
+                        /// Panics if the value does not match the regex, which is possible only
+                        /// if it was created with `new_unchecked()`.
+                        #vis fn #method(&self) -> &str {
+                            // The value is validated, so it matches the regex.
+                            let range = #capture.expect("Nutype failed to capture a group of a valid value");
+                            &self.0[range]
+                        }
+                    }
+                };
+                accessors.push(accessor);
+            }
+        }
+        if regexes.is_empty() {
+            return quote!();
+        }
+
+        quote! {
+            #(#regexes)*
+
+            impl #type_name {
+                #(#accessors)*
+            }
+        }
+    }
+
+    // Standard validation of strings does not need an owned `String`.
+    fn gen_validate_param_type(
+        inner_type: &Self::InnerType,
+        validation: &Validation<Self::Validator>,
+    ) -> TokenStream {
+        match validation {
+            Validation::Standard { .. } => quote!(&str),
+            Validation::Custom { .. } => quote!(&#inner_type),
+        }
+    }
+
     fn gen_fn_validate(
         _inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        report: &ValidationReport,
+        error_variants: ErrorVariants,
         const_fn: ConstFn,
     ) -> TokenStream {
         // Indicates that `chars_count` variable needs to be set, which is used within
@@ -95,73 +345,317 @@ impl GenerateNewtype for StringNewtype {
         // Indicates that `utf16_count` variable needs to be set, which is used within
         // len_utf16_min and len_utf16_max validations.
         let mut requires_utf16_count = false;
+        // Indicates that `grapheme_count` variable needs to be set, which is used within
+        // len_grapheme_min and len_grapheme_max validations.
+        let mut requires_grapheme_count = false;
 
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
                 StringValidator::LenCharMax(max_len) => {
                     requires_chars_count = true;
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenCharMaxViolated),
+                        quote!(max: #max_len, actual: chars_count),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if chars_count > #max_len {
-                            return Err(#error_type_path::LenCharMaxViolated);
+                            #violation
                         }
                     )
                 }
                 StringValidator::LenCharMin(min_len) => {
                     requires_chars_count = true;
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenCharMinViolated),
+                        quote!(min: #min_len, actual: chars_count),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if chars_count < #min_len {
-                            return Err(#error_type_path::LenCharMinViolated);
+                            #violation
                         }
                     )
                 }
                 StringValidator::LenUtf16Max(max_len) => {
                     requires_utf16_count = true;
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenUtf16MaxViolated),
+                        quote!(max: #max_len, actual: utf16_count),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if utf16_count > #max_len {
-                            return Err(#error_type_path::LenUtf16MaxViolated);
+                            #violation
                         }
                     )
                 }
                 StringValidator::LenUtf16Min(min_len) => {
                     requires_utf16_count = true;
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenUtf16MinViolated),
+                        quote!(min: #min_len, actual: utf16_count),
+                    );
+                    let violation = gen_report_violation(report, error);
                     quote!(
                         if utf16_count < #min_len {
-                            return Err(#error_type_path::LenUtf16MinViolated);
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::LenGraphemeMax(max_len) => {
+                    requires_grapheme_count = true;
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenGraphemeMaxViolated),
+                        quote!(max: #max_len, actual: grapheme_count),
+                    );
+                    let violation = gen_report_violation(report, error);
+                    quote!(
+                        if grapheme_count > #max_len {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::LenGraphemeMin(min_len) => {
+                    requires_grapheme_count = true;
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenGraphemeMinViolated),
+                        quote!(min: #min_len, actual: grapheme_count),
+                    );
+                    let violation = gen_report_violation(report, error);
+                    quote!(
+                        if grapheme_count < #min_len {
+                            #violation
                         }
                     )
                 }
                 StringValidator::NotEmpty => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::NotEmptyViolated));
                     quote!(
                         if val.is_empty() {
-                            return Err(#error_type_path::NotEmptyViolated);
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::LenBytesMax(max_len) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenBytesMaxViolated),
+                        quote!(max: #max_len, actual: val.len()),
+                    );
+                    let violation = gen_report_violation(report, error);
+                    quote!(
+                        if val.len() > #max_len {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::LenBytesMin(min_len) => {
+                    let error = gen_error_variant(
+                        error_variants,
+                        quote!(#error_type_path::LenBytesMinViolated),
+                        quote!(min: #min_len, actual: val.len()),
+                    );
+                    let violation = gen_report_violation(report, error);
+                    quote!(
+                        if val.len() < #min_len {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::Ascii => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::AsciiViolated));
+                    quote!(
+                        if !val.is_ascii() {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::Alphanumeric => {
+                    let violation = gen_report_violation(
+                        report,
+                        quote!(#error_type_path::AlphanumericViolated),
+                    );
+                    quote!(
+                        if !val.chars().all(char::is_alphanumeric) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::Printable => {
+                    // Printable are graphic characters and the regular space,
+                    // other whitespaces (e.g. tabs, line breaks) and control characters are not.
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::PrintableViolated));
+                    quote!(
+                        if !val.chars().all(|c| c == ' ' || !(c.is_control() || c.is_whitespace())) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::NoControlChars => {
+                    let violation = gen_report_violation(
+                        report,
+                        quote!(#error_type_path::NoControlCharsViolated),
+                    );
+                    quote!(
+                        if val.chars().any(char::is_control) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::StartsWith(prefix) => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::StartsWithViolated));
+                    quote!(
+                        if !val.starts_with(#prefix) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::EndsWith(suffix) => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::EndsWithViolated));
+                    quote!(
+                        if !val.ends_with(#suffix) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::Contains(substring) => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::ContainsViolated));
+                    quote!(
+                        if !val.contains(#substring) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::NotContains(substring) => {
+                    let violation = gen_report_violation(
+                        report,
+                        quote!(#error_type_path::NotContainsViolated),
+                    );
+                    quote!(
+                        if val.contains(#substring) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::Email
+                | StringValidator::Url
+                | StringValidator::Uuid
+                | StringValidator::Ipv4
+                | StringValidator::Ipv6
+                | StringValidator::Hex
+                | StringValidator::Base64
+                | StringValidator::Semver
+                | StringValidator::Iso8601Date
+                | StringValidator::Luhn
+                | StringValidator::Iban
+                | StringValidator::Isbn
+                | StringValidator::Ean
+                | StringValidator::Iso3166Alpha2
+                | StringValidator::Iso3166Alpha3
+                | StringValidator::Iso4217
+                | StringValidator::Bcp47 => {
+                    // The checks are defined within `nutype::formats`, `nutype::checksums` and `nutype::iso_codes` modules.
+                    let (module, check_fn) = validator
+                        .builtin_check_fn()
+                        .expect("built-in validator must have a check function");
+                    let module = format_ident!("{module}");
+                    let check_fn = format_ident!("{check_fn}");
+                    let variant = format_ident!("{}Violated", format!("{:?}", validator.kind()));
+                    let violation = gen_report_violation(report, quote!(#error_type_path::#variant));
+                    quote!(
+                        if !::nutype::#module::#check_fn(val) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::OneOf(values) => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::OneOfViolated));
+                    quote!(
+                        if !::core::matches!(val, #(#values)|*) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::Slug { separator } => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::SlugViolated));
+                    quote!(
+                        if !::nutype::__private::is_slug(val, #separator) {
+                            #violation
+                        }
+                    )
+                }
+                StringValidator::Identifier => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::IdentifierViolated));
+                    quote!(
+                        if !::nutype::__private::is_identifier(val) {
+                            #violation
                         }
                     )
                 }
                 StringValidator::Predicate(typed_custom_function) => {
+                    let violation =
+                        gen_report_violation(report, quote!(#error_type_path::PredicateViolated));
                     quote!(
                         if !(#typed_custom_function)(&val) {
-                            return Err(#error_type_path::PredicateViolated);
+                            #violation
                         }
                     )
                 }
-                StringValidator::Regex(regex_def) => {
-                    match regex_def {
+                StringValidator::Regex(rule) | StringValidator::NotRegex(rule) => {
+                    let variant = validator
+                        .regex_error_variant()
+                        .expect("regex rule must have an error variant");
+                    let violation = gen_report_violation(report, quote!(#error_type_path::#variant));
+                    // `not_regex` is violated, when the value matches.
+                    let maybe_not = if matches!(validator, StringValidator::Regex(_)) {
+                        quote!(!)
+                    } else {
+                        quote!()
+                    };
+                    match &rule.def {
                         RegexDef::StringLiteral(regex_str_lit) => {
+                            let regex_name = gen_regex_name(&variant);
+                            let regex_static = if rule.captures {
+                                // Declared within the module, next to the accessors.
+                                quote!()
+                            } else if rule.dfa {
+                                gen_regex_dfa_static(&regex_name, regex_str_lit, rule.bytes)
+                            } else {
+                                gen_lazy_regex_static(&regex_name, regex_str_lit, rule.bytes)
+                            };
                             quote!(
-                                // Make up a sufficiently unique regex name to ensure that it does
-                                // not clashes with anything import with `use super::*`.
-                                static __NUTYPE_REGEX__: ::std::sync::LazyLock<::regex::Regex> = ::std::sync::LazyLock::new(|| ::regex::Regex::new(#regex_str_lit).expect("Nutype failed to a build a regex"));
-                                if !__NUTYPE_REGEX__.is_match(&val) {
-                                    return Err(#error_type_path::RegexViolated);
+                                #regex_static
+                                if #maybe_not #regex_name.is_match(&val) {
+                                    #violation
                                 }
                             )
-
                         }
                         RegexDef::Path(regex_path) => {
+                            let haystack = if rule.bytes {
+                                quote!(val.as_bytes())
+                            } else {
+                                quote!(&val)
+                            };
                             quote!(
-                                if !#regex_path.is_match(&val) {
-                                    return Err(#error_type_path::RegexViolated);
+                                if #maybe_not #regex_path.is_match(#haystack) {
+                                    #violation
                                 }
                             )
                         }
@@ -170,38 +664,81 @@ impl GenerateNewtype for StringNewtype {
             })
             .collect();
 
-        let chars_count_if_required = if requires_chars_count {
+        let counts_if_required = if requires_chars_count || requires_utf16_count {
+            // Detailed errors report the actual length, so the exact count is needed.
+            let gen_limit =
+                |is_required: bool, maybe_max: Option<&ValueOrExpr<usize>>| match maybe_max {
+                    _ if !is_required => quote!(0),
+                    Some(max_len) if error_variants == ErrorVariants::Unit => quote!(#max_len),
+                    _ => quote!(::core::primitive::usize::MAX),
+                };
+            let char_limit = gen_limit(
+                requires_chars_count,
+                validators.iter().find_map(|validator| match validator {
+                    StringValidator::LenCharMax(max_len) => Some(max_len),
+                    _ => None,
+                }),
+            );
+            let utf16_limit = gen_limit(
+                requires_utf16_count,
+                validators.iter().find_map(|validator| match validator {
+                    StringValidator::LenUtf16Max(max_len) => Some(max_len),
+                    _ => None,
+                }),
+            );
+            let chars_count = if requires_chars_count {
+                quote!(chars_count)
+            } else {
+                quote!(_)
+            };
+            let utf16_count = if requires_utf16_count {
+                quote!(utf16_count)
+            } else {
+                quote!(_)
+            };
             quote!(
-                let chars_count = val.chars().count();
+                let (#chars_count, #utf16_count) = ::nutype::__private::count_chars_and_utf16(val, #char_limit, #utf16_limit);
             )
         } else {
             quote!()
         };
 
-        let utf16_count_if_required = if requires_utf16_count {
+        let grapheme_count_if_required = if requires_grapheme_count {
+            let max_len = validators.iter().find_map(|validator| match validator {
+                StringValidator::LenGraphemeMax(max_len) => Some(max_len),
+                _ => None,
+            });
+            let limit = match max_len {
+                Some(max_len) if error_variants == ErrorVariants::Unit => quote!(#max_len),
+                _ => quote!(::core::primitive::usize::MAX),
+            };
             quote!(
-                let utf16_count = val.encode_utf16().count();
+                let grapheme_count = ::nutype::__private::count_graphemes(val, #limit);
             )
         } else {
             quote!()
         };
 
+        let validation_result = gen_validation_result(report, validations);
+        let returned_error_type_path = report.returned_error_type_path(error_type_path);
+
         quote!(
-            #const_fn fn __validate__(val: &str) -> ::core::result::Result<(), #error_type_path> {
-                #chars_count_if_required
-                #utf16_count_if_required
-                #validations
-                Ok(())
+            #const_fn fn __validate__(val: &str) -> ::core::result::Result<(), #returned_error_type_path> {
+                #counts_if_required
+                #grapheme_count_if_required
+                #validation_result
             }
         )
     }
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        _inner_type: &Self::InnerType,
         error_type_path: &ErrorTypePath,
         validators: &[Self::Validator],
+        error_variants: ErrorVariants,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, error_type_path, validators)
+        gen_validation_error_type(type_name, error_type_path, validators, error_variants)
     }
 
     fn gen_traits(
@@ -250,3 +787,59 @@ impl GenerateNewtype for StringNewtype {
         }
     }
 }
+
+/// Makes up a sufficiently unique regex name to ensure that it does not clash with anything
+/// imported with `use super::*`. Every rule has its own regex, named after its error variant.
+fn gen_regex_name(variant: &syn::Ident) -> syn::Ident {
+    format_ident!("__NUTYPE_REGEX_{}__", variant.to_string().to_uppercase())
+}
+
+/// Declares the regex of a string literal, that is compiled on its first use.
+fn gen_lazy_regex_static(
+    regex_name: &syn::Ident,
+    regex_str_lit: &syn::LitStr,
+    bytes: bool,
+) -> TokenStream {
+    let regex_type = if bytes {
+        quote!(::nutype::__private::regex::LazyBytesRegex)
+    } else {
+        quote!(::nutype::__private::regex::LazyRegex)
+    };
+    quote!(
+        static #regex_name: #regex_type = #regex_type::new(#regex_str_lit);
+    )
+}
+
+/// Embeds the DFA of `regex(pattern = "...", dfa)` rule, that is built at expansion time.
+/// Both byte orders are embedded, the one of the target is picked by `cfg`.
+#[cfg(feature = "regex_dfa")]
+fn gen_regex_dfa_static(
+    regex_name: &syn::Ident,
+    regex_str_lit: &syn::LitStr,
+    bytes: bool,
+) -> TokenStream {
+    let dfa = super::regex_dfa::build_serialized_dfa(&regex_str_lit.value(), bytes)
+        .expect("DFA of the regex is validated before the generation");
+    let little_endian = proc_macro2::Literal::byte_string(&dfa.little_endian);
+    let big_endian = proc_macro2::Literal::byte_string(&dfa.big_endian);
+    quote!(
+        static #regex_name: ::nutype::__private::regex::EmbeddedDfa = ::nutype::__private::regex::EmbeddedDfa {
+            bytes: &::nutype::__private::regex::AlignAs {
+                _align: [],
+                #[cfg(target_endian = "little")]
+                bytes: *#little_endian,
+                #[cfg(target_endian = "big")]
+                bytes: *#big_endian,
+            },
+        };
+    )
+}
+
+#[cfg(not(feature = "regex_dfa"))]
+fn gen_regex_dfa_static(
+    _regex_name: &syn::Ident,
+    _regex_str_lit: &syn::LitStr,
+    _bytes: bool,
+) -> TokenStream {
+    unreachable!("`dfa` option is parsed only with `regex_dfa` feature")
+}
@@ -0,0 +1,301 @@
+//! Sanitization of string values.
+//!
+//! Consecutive built-in sanitizers, that transform the value char by char (e.g. `trim`,
+//! `collapse_whitespace` and `lowercase`), are fused into a single pass, that rewrites the value
+//! within its own buffer. See `nutype::__private::sanitize::rewrite_chars()`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::string::models::StringSanitizer;
+
+pub fn gen_sanitizers(sanitizers: &[StringSanitizer]) -> TokenStream {
+    let mut transformations = Vec::new();
+    let mut run = FusedRun::default();
+    for sanitizer in sanitizers {
+        if !run.push(sanitizer) {
+            transformations.push(run.gen_transformation());
+            run = FusedRun::default();
+            if !run.push(sanitizer) {
+                transformations.push(gen_sanitizer(sanitizer));
+            }
+        }
+    }
+    transformations.push(run.gen_transformation());
+    quote!(#(#transformations)*)
+}
+
+/// Consecutive built-in sanitizers, that are applied in a single pass over the chars.
+///
+/// Trims are applied first by slicing the input, since they give the same result before and
+/// after the case conversions and whitespace sanitizers. It's not the case for
+/// `strip_control_chars`, which removes both whitespace (e.g. `\t`) and non-whitespace
+/// (e.g. zero-width space) chars, so a trim after it starts a new run.
+#[derive(Default)]
+struct FusedRun<'a> {
+    sanitizers: Vec<&'a StringSanitizer>,
+    trim_start: bool,
+    trim_end: bool,
+}
+
+impl<'a> FusedRun<'a> {
+    /// Returns `false` if the sanitizer cannot be fused into the run.
+    fn push(&mut self, sanitizer: &'a StringSanitizer) -> bool {
+        match sanitizer {
+            StringSanitizer::Trim | StringSanitizer::TrimStart | StringSanitizer::TrimEnd => {
+                if self
+                    .sanitizers
+                    .iter()
+                    .any(|s| matches!(s, StringSanitizer::StripControlChars))
+                {
+                    return false;
+                }
+                self.trim_start |= !matches!(sanitizer, StringSanitizer::TrimEnd);
+                self.trim_end |= !matches!(sanitizer, StringSanitizer::TrimStart);
+            }
+            StringSanitizer::Lowercase
+            | StringSanitizer::Uppercase
+            | StringSanitizer::RemoveWhitespace
+            | StringSanitizer::CollapseWhitespace
+            | StringSanitizer::StripControlChars => {}
+            _ => return false,
+        }
+        self.sanitizers.push(sanitizer);
+        true
+    }
+
+    fn gen_transformation(&self) -> TokenStream {
+        let char_sanitizers: Vec<&StringSanitizer> = self
+            .sanitizers
+            .iter()
+            .copied()
+            .filter(|s| !is_trim(s))
+            .collect();
+        if char_sanitizers.is_empty() {
+            // Trims alone do not need to look at every char.
+            let trims = self.sanitizers.iter().map(|s| gen_sanitizer(s));
+            return quote!(#(#trims)*);
+        }
+
+        // The state of `collapse_whitespace`: whether the previous char is whitespace.
+        let mut states = Vec::new();
+        let mut body = quote!(output.push(c););
+        for (index, sanitizer) in char_sanitizers.iter().enumerate().rev() {
+            body = match sanitizer {
+                StringSanitizer::Lowercase => quote!(
+                    for c in c.to_lowercase() {
+                        #body
+                    }
+                ),
+                StringSanitizer::Uppercase => quote!(
+                    for c in c.to_uppercase() {
+                        #body
+                    }
+                ),
+                StringSanitizer::RemoveWhitespace => quote!(
+                    if !c.is_whitespace() {
+                        #body
+                    }
+                ),
+                StringSanitizer::StripControlChars => quote!(
+                    if !::nutype::__private::is_control_or_zero_width(c) {
+                        #body
+                    }
+                ),
+                StringSanitizer::CollapseWhitespace => {
+                    let prev_is_whitespace = format_ident!("prev_is_whitespace_{index}");
+                    states.push(quote!(let mut #prev_is_whitespace = false;));
+                    quote!(
+                        let is_whitespace = c.is_whitespace();
+                        let is_extra = is_whitespace && #prev_is_whitespace;
+                        #prev_is_whitespace = is_whitespace;
+                        if !is_extra {
+                            let c = if is_whitespace { ' ' } else { c };
+                            #body
+                        }
+                    )
+                }
+                _ => unreachable!("only char by char sanitizers are fused"),
+            };
+        }
+
+        let trim_start = self.trim_start;
+        let trim_end = self.trim_end;
+        let fused = quote!(
+            #(#states)*
+            ::nutype::__private::sanitize::rewrite_chars(&mut value, #trim_start, #trim_end, |c, output| {
+                #body
+            });
+        );
+
+        if char_sanitizers
+            .iter()
+            .any(|s| matches!(s, StringSanitizer::Lowercase))
+        {
+            // `str::to_lowercase()` turns `Σ` into `ς` at the end of a word,
+            // which cannot be done char by char.
+            let sequential = self.sanitizers.iter().map(|s| gen_sanitizer(s));
+            quote!(
+                if value.contains('Σ') {
+                    #(#sequential)*
+                } else {
+                    #fused
+                }
+            )
+        } else {
+            quote!({ #fused })
+        }
+    }
+}
+
+fn is_trim(sanitizer: &StringSanitizer) -> bool {
+    matches!(
+        sanitizer,
+        StringSanitizer::Trim | StringSanitizer::TrimStart | StringSanitizer::TrimEnd
+    )
+}
+
+/// Applies a single sanitizer to `value`.
+fn gen_sanitizer(sanitizer: &StringSanitizer) -> TokenStream {
+    match sanitizer {
+        StringSanitizer::Trim => {
+            quote!(
+                let end = value.trim_end().len();
+                value.truncate(end);
+                let start = value.len() - value.trim_start().len();
+                if start > 0 {
+                    value.replace_range(..start, "");
+                }
+            )
+        }
+        StringSanitizer::TrimStart => {
+            quote!(
+                let start = value.len() - value.trim_start().len();
+                if start > 0 {
+                    value.replace_range(..start, "");
+                }
+            )
+        }
+        StringSanitizer::TrimEnd => {
+            quote!(
+                let end = value.trim_end().len();
+                value.truncate(end);
+            )
+        }
+        // The case conversions are fused with the other sanitizers, so they are applied
+        // separately only if the fused pass cannot be used (see `FusedRun::gen_transformation()`).
+        StringSanitizer::Lowercase => {
+            quote!(
+                value = value.to_lowercase();
+            )
+        }
+        StringSanitizer::Uppercase => {
+            quote!(
+                value = value.to_uppercase();
+            )
+        }
+        StringSanitizer::RemoveWhitespace => {
+            quote!(
+                value.retain(|c| !c.is_whitespace());
+            )
+        }
+        StringSanitizer::CollapseWhitespace => {
+            quote!(
+                let mut prev_is_whitespace = false;
+                let is_collapsed = value.chars().all(|c| {
+                    let is_extra = c.is_whitespace() && (prev_is_whitespace || c != ' ');
+                    prev_is_whitespace = c.is_whitespace();
+                    !is_extra
+                });
+                if !is_collapsed {
+                    let mut collapsed = String::with_capacity(value.len());
+                    let mut prev_is_whitespace = false;
+                    for c in value.chars() {
+                        if !c.is_whitespace() {
+                            collapsed.push(c);
+                        } else if !prev_is_whitespace {
+                            collapsed.push(' ');
+                        }
+                        prev_is_whitespace = c.is_whitespace();
+                    }
+                    value = collapsed;
+                }
+            )
+        }
+        StringSanitizer::StripControlChars => {
+            quote!(
+                value.retain(|c| !::nutype::__private::is_control_or_zero_width(c));
+            )
+        }
+        StringSanitizer::Truncate {
+            len,
+            graphemes: false,
+        } => {
+            quote!(
+                if let Some((end, _)) = value.char_indices().nth(#len) {
+                    value.truncate(end);
+                }
+            )
+        }
+        StringSanitizer::Truncate {
+            len,
+            graphemes: true,
+        } => {
+            quote!(
+                ::nutype::__private::unicode::truncate_graphemes(&mut value, #len);
+            )
+        }
+        StringSanitizer::Replace { from, to } => {
+            quote!(
+                if value.contains(#from) {
+                    value = value.replace(#from, #to);
+                }
+            )
+        }
+        StringSanitizer::PadStart { len, fill } => {
+            quote!(
+                let chars_count = value.chars().count();
+                if chars_count < #len {
+                    let padding: String = ::core::iter::repeat_n(#fill, #len - chars_count).collect();
+                    value.insert_str(0, &padding);
+                }
+            )
+        }
+        StringSanitizer::Nfc => {
+            quote!(
+                ::nutype::__private::unicode::nfc(&mut value);
+            )
+        }
+        StringSanitizer::Nfd => {
+            quote!(
+                ::nutype::__private::unicode::nfd(&mut value);
+            )
+        }
+        StringSanitizer::Nfkc => {
+            quote!(
+                ::nutype::__private::unicode::nfkc(&mut value);
+            )
+        }
+        StringSanitizer::Nfkd => {
+            quote!(
+                ::nutype::__private::unicode::nfkd(&mut value);
+            )
+        }
+        StringSanitizer::Casefold => {
+            quote!(
+                ::nutype::__private::unicode::casefold(&mut value);
+            )
+        }
+        StringSanitizer::Slugify { separator } => {
+            quote!(
+                ::nutype::__private::unicode::slugify(&mut value, #separator);
+            )
+        }
+        StringSanitizer::With(typed_custom_function) => {
+            // The custom function may either take and return `String` or take `&mut String`.
+            quote!(
+                ::nutype::__private::SanitizeWith::sanitize_with(#typed_custom_function, &mut value);
+            )
+        }
+    }
+}
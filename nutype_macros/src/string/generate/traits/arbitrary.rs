@@ -1,10 +1,12 @@
 use kinded::Kinded;
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
 use crate::{
     common::models::{TypeName, Validation, ValueOrExpr},
-    string::models::{StringGuard, StringSanitizer, StringValidator},
+    string::models::{
+        RegexDef, StringGuard, StringSanitizer, StringValidator, StringValidatorKind,
+    },
     utils::issue_reporter::{Issue, build_github_link_with_issue},
 };
 
@@ -52,16 +54,22 @@ pub fn gen_impl_trait_arbitrary(
 
 fn gen_size_hint(maybe_spec: &Option<Specification>) -> TokenStream {
     match maybe_spec {
-        Some(spec) => {
-            let Specification {
-                min_len, max_len, ..
-            } = spec;
+        Some(Specification::Length {
+            min_len, max_len, ..
+        }) => {
             quote!(
                 let min_size_hint = #min_len * core::mem::size_of::<char>();
                 let max_size_hint = #max_len * core::mem::size_of::<char>();
                 (min_size_hint, Some(max_size_hint))
             )
         }
+        // The amount of consumed data depends on the format or the regex and random choices.
+        Some(Specification::Format(_) | Specification::Regex { .. }) => quote!((0, None)),
+        Some(Specification::OneOf(values)) => {
+            let min_size_hint = values.iter().map(|v| v.value().len()).min().unwrap_or(0);
+            let max_size_hint = values.iter().map(|v| v.value().len()).max().unwrap_or(0);
+            quote!((#min_size_hint, Some(#max_size_hint)))
+        }
         None => {
             // That corresponds to `quote!(u.arbitrary()?)` implementation
             quote!(<String as ::arbitrary::Arbitrary<'_>>::size_hint(depth))
@@ -71,7 +79,27 @@ fn gen_size_hint(maybe_spec: &Option<Specification>) -> TokenStream {
 
 fn gen_generate_valid_inner_value(maybe_spec: &Option<Specification>) -> TokenStream {
     match maybe_spec {
-        Some(spec) => gen_generate_valid_inner_value_with_validators(spec),
+        Some(Specification::Length {
+            sanitizers,
+            validators,
+            min_len,
+            max_len,
+        }) => {
+            gen_generate_valid_inner_value_with_validators(sanitizers, validators, min_len, max_len)
+        }
+        Some(Specification::OneOf(values)) => {
+            quote!(
+                let value: &str = u.choose(&[#(#values),*])?;
+                value.to_string()
+            )
+        }
+        Some(Specification::Format(format)) => gen_generate_format_value(*format),
+        Some(Specification::Regex {
+            node,
+            min_len,
+            max_len,
+            has_sanitizers,
+        }) => gen_generate_regex_value(node, min_len, max_len, *has_sanitizers),
         None => {
             // When there is no validation, then we can just simply delegate to the arbitrary
             // crate, and the job is done.
@@ -85,6 +113,15 @@ fn gen_generate_valid_inner_value(maybe_spec: &Option<Specification>) -> TokenSt
 #[derive(Kinded)]
 enum RelevantSanitizer {
     Trim,
+    TrimStart,
+    TrimEnd,
+    RemoveWhitespace,
+    CollapseWhitespace,
+    StripControlChars,
+    Normalization,
+    Truncate(ValueOrExpr<usize>),
+    Replace(syn::LitStr),
+    Slugify(char),
 }
 
 /// Subset of StringValidator, which is is possible to handle and is relevant for generating
@@ -93,13 +130,36 @@ enum RelevantSanitizer {
 enum RelevantValidator {
     LenCharMin(ValueOrExpr<usize>),
     LenCharMax(ValueOrExpr<usize>),
+    Slug(char),
+    Identifier,
 }
 
 /// Final specification to generate an arbitrary valid string
-struct Specification {
-    has_trim: bool,
-    min_len: ValueOrExpr<usize>,
-    max_len: ValueOrExpr<usize>,
+#[allow(clippy::large_enum_variant)]
+enum Specification {
+    /// Random chars with the length within the bounds.
+    Length {
+        sanitizers: Vec<RelevantSanitizer>,
+        validators: Vec<RelevantValidator>,
+        min_len: ValueOrExpr<usize>,
+        max_len: ValueOrExpr<usize>,
+    },
+
+    /// One of the allowed values.
+    OneOf(Vec<syn::LitStr>),
+
+    /// A value of the format validator, e.g. `email`.
+    Format(StringValidatorKind),
+
+    /// A value, that matches the regex, with the length within the bounds.
+    Regex {
+        /// Expression of type `nutype::__private::regex_arbitrary::Node`.
+        node: TokenStream,
+        min_len: ValueOrExpr<usize>,
+        max_len: Option<ValueOrExpr<usize>>,
+        /// The sanitizers may change the value, so it has to be validated.
+        has_sanitizers: bool,
+    },
 }
 
 /// If max length is not specified, then sum of min_len + this offset will be used.
@@ -113,12 +173,62 @@ fn build_specification(guard: &StringGuard) -> Result<Option<Specification>, syn
             validation,
         } => {
             let validators = get_validators(validation)?;
+
+            // Every value of `one_of` is checked against the other validators and sanitizers
+            // at compile time, so any of them is valid, unless it's something we cannot check.
+            if let Some(values) = validators.iter().find_map(|v| match v {
+                StringValidator::OneOf(values) => Some(values),
+                _ => None,
+            }) {
+                if let Some(v) = validators.iter().find(|v| {
+                    matches!(
+                        v,
+                        StringValidator::Predicate(_)
+                            | StringValidator::Regex(_)
+                            | StringValidator::NotRegex(_)
+                    )
+                }) {
+                    let msg = format!(
+                        "It's not possible to derive `Arbitrary` trait for a type with `{}` validator.\nYou have to implement `Arbitrary` trait on you own.",
+                        v.kind()
+                    );
+                    return Err(syn::Error::new(Span::call_site(), msg));
+                }
+                filter_sanitizers(sanitizers)?;
+                return Ok(Some(Specification::OneOf(values.clone())));
+            }
+
+            // Values of a format are generated directly, so the other validators cannot be
+            // taken into account (except `not_empty`, since no format allows empty strings).
+            if let Some(format) = validators.iter().find(|v| v.builtin_check_fn().is_some()) {
+                if let Some(v) = validators
+                    .iter()
+                    .find(|v| v.kind() != format.kind() && !matches!(v, StringValidator::NotEmpty))
+                {
+                    let msg = format!(
+                        "It's not possible to derive `Arbitrary` trait for a type with `{}` validator combined with `{}` validator.\nYou have to implement `Arbitrary` trait on you own.",
+                        format.kind(),
+                        v.kind()
+                    );
+                    return Err(syn::Error::new(Span::call_site(), msg));
+                }
+                filter_sanitizers(sanitizers)?;
+                return Ok(Some(Specification::Format(format.kind())));
+            }
+
+            // Values are generated from the syntax tree of the regex.
+            if validators
+                .iter()
+                .any(|v| matches!(v, StringValidator::Regex(_)))
+            {
+                filter_sanitizers(sanitizers)?;
+                let spec = build_regex_specification(validators, !sanitizers.is_empty())?;
+                return Ok(Some(spec));
+            }
+
             let relevant_validators = filter_validators(validators)?;
             let relevant_sanitizers = filter_sanitizers(sanitizers)?;
 
-            let has_trim = relevant_sanitizers
-                .iter()
-                .any(|s| matches!(s, RelevantSanitizer::Trim));
             let min_len = relevant_validators
                 .iter()
                 .find_map(|v| {
@@ -129,6 +239,17 @@ fn build_specification(guard: &StringGuard) -> Result<Option<Specification>, syn
                     }
                 })
                 .unwrap_or_else(|| ValueOrExpr::Value(0));
+            // Neither a slug nor an identifier can be empty.
+            let min_len = if relevant_validators.iter().any(|v| {
+                matches!(
+                    v,
+                    RelevantValidator::Slug(_) | RelevantValidator::Identifier
+                )
+            }) {
+                max_len_or_expr(min_len, 1)
+            } else {
+                min_len
+            };
             let max_len = relevant_validators
                 .iter()
                 .find_map(|v| {
@@ -139,9 +260,18 @@ fn build_specification(guard: &StringGuard) -> Result<Option<Specification>, syn
                     }
                 })
                 .unwrap_or_else(|| min_len.clone() + DEFAULT_LEN_OFFSET);
+            // Values not longer than `truncate` are never truncated (a grapheme cluster
+            // consists of at least one char), so the trailing whitespaces are not exposed.
+            let max_len = relevant_sanitizers
+                .iter()
+                .fold(max_len, |max_len, s| match s {
+                    RelevantSanitizer::Truncate(len) => min_len_or_expr(max_len, len),
+                    _ => max_len,
+                });
 
-            let spec = Specification {
-                has_trim,
+            let spec = Specification::Length {
+                sanitizers: relevant_sanitizers,
+                validators: relevant_validators,
                 min_len,
                 max_len,
             };
@@ -150,6 +280,84 @@ fn build_specification(guard: &StringGuard) -> Result<Option<Specification>, syn
     }
 }
 
+/// Only the length validators can be combined with a `regex` literal, since the length is taken
+/// into account when the value is generated.
+fn build_regex_specification(
+    validators: &[StringValidator],
+    has_sanitizers: bool,
+) -> Result<Specification, syn::Error> {
+    let mut maybe_regex_lit = None;
+    let mut min_len = ValueOrExpr::Value(0);
+    let mut max_len = None;
+    let mut not_empty = false;
+    for v in validators {
+        match v {
+            StringValidator::Regex(rule) if maybe_regex_lit.is_none() => match &rule.def {
+                RegexDef::StringLiteral(lit) => maybe_regex_lit = Some(lit),
+                RegexDef::Path(_) => {
+                    let msg = "It's not possible to derive `Arbitrary` trait for a type with `regex` validator given by path, since the regex is not known at compile time.\nUse a string literal or implement `Arbitrary` trait on you own.";
+                    return Err(syn::Error::new(Span::call_site(), msg));
+                }
+            },
+            StringValidator::LenCharMin(len) => min_len = len.clone(),
+            StringValidator::NotEmpty => not_empty = true,
+            StringValidator::LenCharMax(len) => max_len = Some(len.clone()),
+            _ => {
+                let msg = format!(
+                    "It's not possible to derive `Arbitrary` trait for a type with `regex` validator combined with `{}` validator.\nYou have to implement `Arbitrary` trait on you own.",
+                    v.kind()
+                );
+                return Err(syn::Error::new(Span::call_site(), msg));
+            }
+        }
+    }
+    if not_empty {
+        min_len = max_len_or_expr(min_len, 1);
+    }
+    let regex_lit = maybe_regex_lit.expect("regex validator must be present");
+    let node = gen_regex_node(&regex_lit.value()).map_err(|reason| {
+        let msg = format!(
+            "It's not possible to derive `Arbitrary` trait for a type with `regex = {:?}`, since {reason}.\nYou have to implement `Arbitrary` trait on you own.",
+            regex_lit.value()
+        );
+        syn::Error::new(regex_lit.span(), msg)
+    })?;
+    Ok(Specification::Regex {
+        node,
+        min_len,
+        max_len,
+        has_sanitizers,
+    })
+}
+
+#[cfg(any(feature = "regex", feature = "regex_lite"))]
+use crate::string::regex_arbitrary::gen_regex_node;
+
+#[cfg(not(any(feature = "regex", feature = "regex_lite")))]
+fn gen_regex_node(_pattern: &str) -> Result<TokenStream, String> {
+    unreachable!("`regex` validator is parsed only with `regex` or `regex_lite` feature")
+}
+
+fn min_len_or_expr(a: ValueOrExpr<usize>, b: &ValueOrExpr<usize>) -> ValueOrExpr<usize> {
+    match (a, b) {
+        (ValueOrExpr::Value(a), ValueOrExpr::Value(b)) => ValueOrExpr::Value(a.min(*b)),
+        (a, b) => {
+            let expr = syn::parse_quote!(::core::cmp::min(#a, #b));
+            ValueOrExpr::Expr(expr)
+        }
+    }
+}
+
+fn max_len_or_expr(a: ValueOrExpr<usize>, b: usize) -> ValueOrExpr<usize> {
+    match a {
+        ValueOrExpr::Value(a) => ValueOrExpr::Value(a.max(b)),
+        a => {
+            let expr = syn::parse_quote!(::core::cmp::max(#a, #b));
+            ValueOrExpr::Expr(expr)
+        }
+    }
+}
+
 fn get_validators(
     validation: &Validation<StringValidator>,
 ) -> Result<&[StringValidator], syn::Error> {
@@ -169,18 +377,52 @@ fn filter_validators(validators: &[StringValidator]) -> Result<Vec<RelevantValid
             StringValidator::LenCharMax(value) => Ok(RelevantValidator::LenCharMax(value.clone())),
             // In context of generating an arbitrary string NotEmpty is the same as LenCharMin(1)
             StringValidator::NotEmpty => Ok(RelevantValidator::LenCharMin(ValueOrExpr::Value(1))),
+            StringValidator::Slug { separator } => Ok(RelevantValidator::Slug(*separator)),
+            StringValidator::Identifier => Ok(RelevantValidator::Identifier),
             StringValidator::Predicate(_) => {
                 let msg = "It's not possible to derive `Arbitrary` trait for a type with `predicate` validator.\nYou have to implement `Arbitrary` trait on you own.";
                 Err(syn::Error::new(Span::call_site(), msg))
             }
-            StringValidator::Regex(_) => {
-                let msg = "It's not possible to derive `Arbitrary` trait for a type with `regex` validator.\nYou have to implement `Arbitrary` trait on you own.";
+            // Handled separately, since they define the whole set of valid values.
+            StringValidator::OneOf(_)
+            | StringValidator::Email
+            | StringValidator::Url
+            | StringValidator::Uuid
+            | StringValidator::Ipv4
+            | StringValidator::Ipv6
+            | StringValidator::Hex
+            | StringValidator::Base64
+            | StringValidator::Semver
+            | StringValidator::Iso8601Date
+            | StringValidator::Luhn
+            | StringValidator::Iban
+            | StringValidator::Isbn
+            | StringValidator::Ean
+            | StringValidator::Iso3166Alpha2
+            | StringValidator::Iso3166Alpha3
+            | StringValidator::Iso4217
+            | StringValidator::Bcp47 => unreachable!("{} is handled before filtering", v.kind()),
+            StringValidator::Regex(_) | StringValidator::NotRegex(_) => {
+                let msg = format!("It's not possible to derive `Arbitrary` trait for a type with `{}` validator.\nYou have to implement `Arbitrary` trait on you own.", v.kind());
                 Err(syn::Error::new(Span::call_site(), msg))
             }
             StringValidator::LenUtf16Min(_) | StringValidator::LenUtf16Max(_) => {
                 let msg = "It's not possible to derive `Arbitrary` trait for a type with `len_utf16_min` or `len_utf16_max` validator.\nYou have to implement `Arbitrary` trait on you own.";
                 Err(syn::Error::new(Span::call_site(), msg))
             }
+            StringValidator::LenBytesMin(_) | StringValidator::LenBytesMax(_) => {
+                let msg = "It's not possible to derive `Arbitrary` trait for a type with `len_bytes_min` or `len_bytes_max` validator.\nYou have to implement `Arbitrary` trait on you own.";
+                Err(syn::Error::new(Span::call_site(), msg))
+            }
+            StringValidator::LenGraphemeMin(_) | StringValidator::LenGraphemeMax(_) => {
+                let msg = "It's not possible to derive `Arbitrary` trait for a type with `len_grapheme_min` or `len_grapheme_max` validator.\nYou have to implement `Arbitrary` trait on you own.";
+                Err(syn::Error::new(Span::call_site(), msg))
+            }
+            StringValidator::Ascii | StringValidator::Alphanumeric | StringValidator::Printable | StringValidator::NoControlChars
+            | StringValidator::StartsWith(_) | StringValidator::EndsWith(_) | StringValidator::Contains(_) | StringValidator::NotContains(_) => {
+                let msg = format!("It's not possible to derive `Arbitrary` trait for a type with `{}` validator.\nYou have to implement `Arbitrary` trait on you own.", v.kind());
+                Err(syn::Error::new(Span::call_site(), msg))
+            }
         }
     }).collect()
 }
@@ -191,10 +433,30 @@ fn filter_sanitizers(sanitizers: &[StringSanitizer]) -> Result<Vec<RelevantSanit
             // Trim is relevant, because trimming a space can decrease string length and cause
             // violation of len_char_min validation.
             StringSanitizer::Trim => Some(Ok(RelevantSanitizer::Trim)),
+            StringSanitizer::TrimStart => Some(Ok(RelevantSanitizer::TrimStart)),
+            StringSanitizer::TrimEnd => Some(Ok(RelevantSanitizer::TrimEnd)),
+            // Same as trim, but whitespaces in the middle are removed or collapsed too.
+            StringSanitizer::RemoveWhitespace => Some(Ok(RelevantSanitizer::RemoveWhitespace)),
+            StringSanitizer::CollapseWhitespace => Some(Ok(RelevantSanitizer::CollapseWhitespace)),
+            StringSanitizer::StripControlChars => Some(Ok(RelevantSanitizer::StripControlChars)),
+            StringSanitizer::Truncate { len, .. } => Some(Ok(RelevantSanitizer::Truncate(len.clone()))),
+            StringSanitizer::Replace { from, .. } => Some(Ok(RelevantSanitizer::Replace(from.clone()))),
+            // Only the words of a slug survive `slugify` unchanged.
+            StringSanitizer::Slugify { separator } => Some(Ok(RelevantSanitizer::Slugify(*separator))),
+            // Padding makes short values longer, but not longer than `len_char_max`
+            // (it's checked at compile time), so it's safe to ignore it.
+            StringSanitizer::PadStart { .. } => None,
             // lowercase and uppercase sanitizers do not overlap with any of the validation rules,
             // so we can ignore them
             StringSanitizer::Lowercase => None,
             StringSanitizer::Uppercase => None,
+            // Normalization and case folding may change the number of chars of a non-ASCII
+            // string (e.g. `ß` is folded into `ss`).
+            StringSanitizer::Nfc
+            | StringSanitizer::Nfd
+            | StringSanitizer::Nfkc
+            | StringSanitizer::Nfkd
+            | StringSanitizer::Casefold => Some(Ok(RelevantSanitizer::Normalization)),
             StringSanitizer::With(_) => {
                 let msg = "It's not possible to derive `Arbitrary` trait for a type with `with` sanitizer.\nYou have to implement `Arbitrary` trait on you own.";
                 Some(Err(syn::Error::new(Span::call_site(), msg)))
@@ -203,46 +465,102 @@ fn filter_sanitizers(sanitizers: &[StringSanitizer]) -> Result<Vec<RelevantSanit
     }).collect()
 }
 
-fn gen_generate_valid_inner_value_with_validators(spec: &Specification) -> TokenStream {
-    let Specification {
-        has_trim,
-        min_len,
-        max_len,
-    } = spec;
+fn gen_generate_valid_inner_value_with_validators(
+    sanitizers: &[RelevantSanitizer],
+    validators: &[RelevantValidator],
+    min_len: &ValueOrExpr<usize>,
+    max_len: &ValueOrExpr<usize>,
+) -> TokenStream {
+    let has = |kind: RelevantSanitizerKind| sanitizers.iter().any(|s| s.kind() == kind);
+    let is_identifier = validators
+        .iter()
+        .any(|v| matches!(v, RelevantValidator::Identifier));
+    let maybe_slug_separator = validators
+        .iter()
+        .find_map(|v| match v {
+            RelevantValidator::Slug(separator) => Some(*separator),
+            _ => None,
+        })
+        .or_else(|| {
+            sanitizers.iter().find_map(|s| match s {
+                RelevantSanitizer::Slugify(separator) => Some(*separator),
+                _ => None,
+            })
+        });
 
-    if *has_trim {
+    // Slugs and identifiers consist of ASCII words. The separator of a slug is never placed
+    // at the edges or twice in a row (see the rejections below), and it's not used at all
+    // if an identifier cannot contain it.
+    let maybe_words_alphabet = match (maybe_slug_separator, is_identifier) {
+        (Some(separator), true) if separator != '_' => Some(LOWERCASE_ALPHANUMERIC.to_string()),
+        (Some(separator), _) => Some(format!("{LOWERCASE_ALPHANUMERIC}{separator}")),
+        (None, true) => Some(format!("{ALPHANUMERIC}_")),
+        (None, false) => None,
+    };
+    let gen_char = if let Some(alphabet) = &maybe_words_alphabet {
+        quote!(char::from(*u.choose(#alphabet.as_bytes())?))
+    } else if has(RelevantSanitizerKind::Normalization) {
+        // ASCII strings are not changed by normalization, and case folding of them is
+        // the same as lowercasing, so the length is preserved.
+        quote!(char::from(u.int_in_range(0u8..=0x7F)?))
+    } else {
+        quote!(u.arbitrary::<char>()?)
+    };
+
+    // Every generated char must survive the sanitizers, so the length of the output
+    // is not changed by them.
+    let mut rejections: Vec<TokenStream> = Vec::new();
+    if has(RelevantSanitizerKind::RemoveWhitespace) {
+        rejections.push(quote!(ch.is_whitespace()));
+    }
+    if has(RelevantSanitizerKind::CollapseWhitespace) {
+        rejections.push(quote!(
+            ch.is_whitespace() && (ch != ' ' || output.ends_with(char::is_whitespace))
+        ));
+    }
+    if has(RelevantSanitizerKind::Trim) || has(RelevantSanitizerKind::TrimStart) {
+        rejections.push(quote!(ch.is_whitespace() && output.is_empty()));
+    }
+    if has(RelevantSanitizerKind::Trim) || has(RelevantSanitizerKind::TrimEnd) {
+        rejections.push(quote!(ch.is_whitespace() && len + 1 == target_len));
+    }
+    if has(RelevantSanitizerKind::StripControlChars) {
+        rejections.push(quote!(::nutype::__private::is_control_or_zero_width(ch)));
+    }
+    // A value never contains the replaced string, if it never contains its first char.
+    let replaced_chars: Vec<char> = sanitizers
+        .iter()
+        .filter_map(|s| match s {
+            RelevantSanitizer::Replace(from) => from.value().chars().next(),
+            _ => None,
+        })
+        .collect();
+    if !replaced_chars.is_empty() {
+        rejections.push(quote!([#(#replaced_chars),*].contains(&ch)));
+    }
+    if let Some(separator) = maybe_slug_separator {
+        rejections.push(quote!(
+            ch == #separator && (output.is_empty() || len + 1 == target_len || output.ends_with(#separator))
+        ));
+    }
+    if is_identifier {
+        rejections.push(quote!(ch.is_ascii_digit() && output.is_empty()));
+    }
+    let fallback_char = LOWERCASE_ALPHANUMERIC
+        .chars()
+        .find(|c| !replaced_chars.contains(c) && (!is_identifier || !c.is_ascii_digit()))
+        .unwrap_or('a');
+
+    if rejections.is_empty() {
         quote!(
             // Pick randomly a target length
             let target_len = u.int_in_range((#min_len)..=(#max_len))?;
             // Generate string `output` that matches the target_len
             let mut output = String::with_capacity(target_len * 2);
             for _ in 0..target_len {
-                let ch: char = u.arbitrary()?;
+                let ch: char = #gen_char;
                 output.push(ch);
             }
-            // Make sure that the generated string matches the target_len
-            // after trimming the spaces.
-            loop {
-                let count = output.trim().chars().count();
-
-                match count.cmp(&target_len) {
-                    core::cmp::Ordering::Equal => {
-                        break;
-                    }
-                    core::cmp::Ordering::Less => {
-                        // Try luck one more time: trim the spaces and add another char.
-                        // NOTE: This is inefficient, but it's not expected to happen often.
-                        output = output.trim().to_string();
-                        let new_char: char = u.arbitrary()?;
-                        output.push(new_char);
-                    }
-                    core::cmp::Ordering::Greater => {
-                        unreachable!(
-                            "This should never happened. Generated string is longer then target_len."
-                        );
-                    }
-                }
-            }
             // Return the output string
             output
         )
@@ -250,14 +568,254 @@ fn gen_generate_valid_inner_value_with_validators(spec: &Specification) -> Token
         quote!(
             // Pick randomly a target length
             let target_len = u.int_in_range((#min_len)..=(#max_len))?;
-            // Generate string `output` that matches the target_len
+            // Generate string `output` that matches the target_len, skipping the chars
+            // which would be changed by the sanitizers.
             let mut output = String::with_capacity(target_len * 2);
-            for _ in 0..target_len {
-                let ch: char = u.arbitrary()?;
+            let mut len = 0;
+            while len < target_len {
+                let mut ch: char = #gen_char;
+                if #(#rejections)||* {
+                    if !u.is_empty() {
+                        continue;
+                    }
+                    // The data is exhausted, so the same char would be generated forever.
+                    ch = #fallback_char;
+                }
                 output.push(ch);
+                len += 1;
             }
             // Return the output string
             output
         )
     }
 }
+
+/// Generates a value from the syntax tree of the regex (see `nutype::__private::regex_arbitrary`).
+/// If the value can be changed by the sanitizers, it's validated and regenerated a few times,
+/// so the generation fails only if the data is exhausted.
+fn gen_generate_regex_value(
+    node: &TokenStream,
+    min_len: &ValueOrExpr<usize>,
+    max_len: &Option<ValueOrExpr<usize>>,
+    has_sanitizers: bool,
+) -> TokenStream {
+    let max_len = match max_len {
+        Some(max_len) => quote!(#max_len),
+        None => quote!(::core::primitive::usize::MAX),
+    };
+    let generate = quote!(
+        ::nutype::__private::regex_arbitrary::generate(
+            &__NUTYPE_ARBITRARY_REGEX__,
+            #min_len,
+            #max_len,
+            &mut |min, max| u.int_in_range(min..=max),
+        )?
+    );
+    let generate = if has_sanitizers {
+        quote!(
+            let mut attempts = 0;
+            loop {
+                let value: String = #generate;
+                if Self::try_new(value.clone()).is_ok() {
+                    break value;
+                }
+                attempts += 1;
+                if attempts == 16 || u.is_empty() {
+                    return Err(::arbitrary::Error::IncorrectFormat);
+                }
+            }
+        )
+    } else {
+        generate
+    };
+    quote!(
+        static __NUTYPE_ARBITRARY_REGEX__: ::nutype::__private::regex_arbitrary::Node = #node;
+        #generate
+    )
+}
+
+const LOWERCASE_ALPHANUMERIC: &str = "abcdefghijklmnopqrstuvwxyz0123456789";
+const ALPHANUMERIC: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Generates a value, that satisfies the format (see `nutype::formats` and `nutype::iso_codes`
+/// for the definitions).
+fn gen_generate_format_value(format: StringValidatorKind) -> TokenStream {
+    match format {
+        StringValidatorKind::Email => {
+            let local = gen_random_string(
+                "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789.!#$%&'*+/=?^_`{|}~-",
+                1,
+                16,
+            );
+            let domain = gen_random_string(LOWERCASE_ALPHANUMERIC, 1, 16);
+            let tld = gen_random_string("abcdefghijklmnopqrstuvwxyz", 2, 6);
+            quote!(format!("{}@{}.{}", #local, #domain, #tld))
+        }
+        StringValidatorKind::Url => {
+            let domain = gen_random_string(LOWERCASE_ALPHANUMERIC, 1, 16);
+            let tld = gen_random_string("abcdefghijklmnopqrstuvwxyz", 2, 6);
+            let path = gen_random_string("abcdefghijklmnopqrstuvwxyz0123456789-._~/", 0, 16);
+            quote!(
+                let scheme: &str = u.choose(&["http", "https", "ftp"])?;
+                format!("{}://{}.{}/{}", scheme, #domain, #tld, #path)
+            )
+        }
+        StringValidatorKind::Uuid => quote!(
+            let value: u128 = u.arbitrary()?;
+            let hex = format!("{value:032x}");
+            format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+        ),
+        StringValidatorKind::Ipv4 => quote!(
+            let octets: [u8; 4] = u.arbitrary()?;
+            ::core::net::Ipv4Addr::from(octets).to_string()
+        ),
+        StringValidatorKind::Ipv6 => quote!(
+            let segments: [u16; 8] = u.arbitrary()?;
+            ::core::net::Ipv6Addr::from(segments).to_string()
+        ),
+        StringValidatorKind::Hex => gen_random_string("0123456789abcdefABCDEF", 1, 32),
+        StringValidatorKind::Base64 => {
+            let data = gen_random_string(
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+                1,
+                8,
+            );
+            quote!(
+                // Groups of 4 chars, the last group may be padded.
+                let groups: String = #data;
+                let mut value: String = groups.chars().flat_map(|c| [c; 4]).collect();
+                let padding = u.int_in_range(0..=2)?;
+                value.truncate(value.len() - padding);
+                value.extend(::core::iter::repeat_n('=', padding));
+                value
+            )
+        }
+        StringValidatorKind::Semver => {
+            let build = gen_random_string(LOWERCASE_ALPHANUMERIC, 1, 8);
+            quote!(
+                let major: u8 = u.arbitrary()?;
+                let minor: u8 = u.arbitrary()?;
+                let patch: u16 = u.arbitrary()?;
+                let mut version = format!("{major}.{minor}.{patch}");
+                if u.arbitrary()? {
+                    let pre_release: &str = u.choose(&["alpha", "beta", "rc"])?;
+                    let number: u8 = u.arbitrary()?;
+                    version = format!("{version}-{pre_release}.{number}");
+                }
+                if u.arbitrary()? {
+                    version = format!("{version}+{}", #build);
+                }
+                version
+            )
+        }
+        StringValidatorKind::Iso8601Date => quote!(
+            let year: u32 = u.int_in_range(0..=9999)?;
+            let month: u32 = u.int_in_range(1..=12)?;
+            let days_in_month = match month {
+                2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+                2 => 28,
+                4 | 6 | 9 | 11 => 30,
+                _ => 31,
+            };
+            let day: u32 = u.int_in_range(1..=days_in_month)?;
+            format!("{year:04}-{month:02}-{day:02}")
+        ),
+        StringValidatorKind::Luhn => {
+            let payload = gen_random_string("0123456789", 1, 18);
+            gen_append_check_char(payload, "0123456789", "is_luhn")
+        }
+        StringValidatorKind::Iban => {
+            let country = gen_random_string("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 2, 2);
+            let account = gen_random_string("ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789", 11, 30);
+            quote!(
+                let country: String = #country;
+                let account: String = #account;
+                // Exactly one pair of check digits is valid.
+                (0..100)
+                    .map(|check| format!("{country}{check:02}{account}"))
+                    .find(|value| ::nutype::checksums::is_iban(value))
+                    .expect("One of the IBAN check digits must be valid")
+            )
+        }
+        StringValidatorKind::Isbn => {
+            let isbn10 = gen_append_check_char(
+                gen_random_string("0123456789", 9, 9),
+                "0123456789X",
+                "is_isbn",
+            );
+            let digits = gen_random_string("0123456789", 9, 9);
+            let isbn13 = gen_append_check_char(
+                quote!({
+                    let prefix: &str = u.choose(&["978", "979"])?;
+                    format!("{prefix}{}", #digits)
+                }),
+                "0123456789",
+                "is_isbn",
+            );
+            quote!(
+                if u.arbitrary()? {
+                    #isbn10
+                } else {
+                    #isbn13
+                }
+            )
+        }
+        StringValidatorKind::Ean => {
+            let payload = quote!({
+                let len: usize = *u.choose(&[7, 12])?;
+                let alphabet: &[u8] = b"0123456789";
+                let mut value = String::with_capacity(len + 1);
+                for _ in 0..len {
+                    value.push(char::from(*u.choose(alphabet)?));
+                }
+                value
+            });
+            gen_append_check_char(payload, "0123456789", "is_ean")
+        }
+        StringValidatorKind::Iso3166Alpha2 => {
+            quote!(u.choose(::nutype::iso_codes::ISO3166_ALPHA2)?.to_string())
+        }
+        StringValidatorKind::Iso3166Alpha3 => {
+            quote!(u.choose(::nutype::iso_codes::ISO3166_ALPHA3)?.to_string())
+        }
+        StringValidatorKind::Iso4217 => quote!(u.choose(::nutype::iso_codes::ISO4217)?.to_string()),
+        StringValidatorKind::Bcp47 => quote!(
+            // A language with an optional region, e.g. `en` or `en-US`.
+            let language: &str = u.choose(::nutype::iso_codes::ISO639_1)?;
+            if u.arbitrary()? {
+                let region: &str = u.choose(::nutype::iso_codes::ISO3166_ALPHA2)?;
+                format!("{language}-{region}")
+            } else {
+                language.to_string()
+            }
+        ),
+        other => unreachable!("`{other}` is not a format validator"),
+    }
+}
+
+/// Generates a block, that completes the payload with the check char, for which the check function
+/// of `nutype::checksums` passes.
+fn gen_append_check_char(payload: TokenStream, check_chars: &str, check_fn: &str) -> TokenStream {
+    let check_fn = format_ident!("{check_fn}");
+    quote!({
+        let payload: String = #payload;
+        #check_chars
+            .chars()
+            .map(|check| format!("{payload}{check}"))
+            .find(|value| ::nutype::checksums::#check_fn(value))
+            .expect("One of the check chars must be valid")
+    })
+}
+
+/// Generates an expression, that returns a random string of the chars from the alphabet.
+fn gen_random_string(alphabet: &str, min_len: usize, max_len: usize) -> TokenStream {
+    quote!({
+        let len = u.int_in_range(#min_len..=#max_len)?;
+        let alphabet: &[u8] = #alphabet.as_bytes();
+        let mut value = String::with_capacity(len);
+        for _ in 0..len {
+            value.push(char::from(*u.choose(alphabet)?));
+        }
+        value
+    })
+}
@@ -1,8 +1,18 @@
 pub mod generate;
 pub mod models;
 pub mod parse;
+pub mod text;
 pub mod validate;
 
+#[cfg(any(feature = "regex", feature = "regex_lite"))]
+pub mod regex_arbitrary;
+
+#[cfg(any(feature = "regex", feature = "regex_lite"))]
+pub mod regex_captures;
+
+#[cfg(feature = "regex_dfa")]
+pub mod regex_dfa;
+
 use crate::common::{
     generate::GenerateNewtype,
     models::{
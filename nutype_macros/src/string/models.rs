@@ -10,12 +10,46 @@ use crate::common::models::{
 
 pub type SpannedStringSanitizer = SpannedItem<StringSanitizer>;
 
-#[derive(Debug, Kinded)]
+#[derive(Debug, Clone, Kinded)]
 #[kinded(display = "snake_case")]
 pub enum StringSanitizer {
     Trim,
+    TrimStart,
+    TrimEnd,
     Lowercase,
     Uppercase,
+    RemoveWhitespace,
+    CollapseWhitespace,
+    StripControlChars,
+    /// Keeps the first `len` chars, or grapheme clusters if `graphemes` is set.
+    Truncate {
+        len: ValueOrExpr<usize>,
+        graphemes: bool,
+    },
+    Replace {
+        from: syn::LitStr,
+        to: syn::LitStr,
+    },
+    /// Prepends `fill` chars until the value is `len` chars long.
+    PadStart {
+        len: ValueOrExpr<usize>,
+        fill: syn::LitChar,
+    },
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    Nfc,
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    Nfd,
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    Nfkc,
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    Nfkd,
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    Casefold,
+    /// Transliterates the value into ASCII, lowercases it and joins the words with `separator`.
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    Slugify {
+        separator: char,
+    },
     With(TypedCustomFunction),
 }
 
@@ -24,7 +58,7 @@ pub enum StringSanitizer {
 
 pub type SpannedStringValidator = SpannedItem<StringValidator>;
 
-#[derive(Debug, Kinded)]
+#[derive(Debug, Clone, Kinded)]
 #[kinded(display = "snake_case")]
 pub enum StringValidator {
     LenCharMin(ValueOrExpr<usize>),
@@ -33,14 +67,165 @@ pub enum StringValidator {
     LenUtf16Min(ValueOrExpr<usize>),
     #[kinded(rename = "len_utf16_max")]
     LenUtf16Max(ValueOrExpr<usize>),
+    LenBytesMin(ValueOrExpr<usize>),
+    LenBytesMax(ValueOrExpr<usize>),
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    LenGraphemeMin(ValueOrExpr<usize>),
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    LenGraphemeMax(ValueOrExpr<usize>),
     NotEmpty,
+    Ascii,
+    Alphanumeric,
+    Printable,
+    NoControlChars,
+    StartsWith(syn::LitStr),
+    EndsWith(syn::LitStr),
+    Contains(syn::LitStr),
+    NotContains(syn::LitStr),
+    OneOf(Vec<syn::LitStr>),
+    /// Lowercase ASCII words joined with `separator`, e.g. `hello-world`.
+    Slug {
+        separator: char,
+    },
+    Identifier,
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Email,
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Url,
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Uuid,
+    #[kinded(rename = "ipv4")]
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Ipv4,
+    #[kinded(rename = "ipv6")]
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Ipv6,
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Hex,
+    #[kinded(rename = "base64")]
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Base64,
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Semver,
+    #[kinded(rename = "iso8601_date")]
+    #[cfg_attr(not(feature = "formats"), allow(dead_code))]
+    Iso8601Date,
+    Luhn,
+    Iban,
+    Isbn,
+    Ean,
+    #[kinded(rename = "iso3166_alpha2")]
+    #[cfg_attr(not(feature = "iso_codes"), allow(dead_code))]
+    Iso3166Alpha2,
+    #[kinded(rename = "iso3166_alpha3")]
+    #[cfg_attr(not(feature = "iso_codes"), allow(dead_code))]
+    Iso3166Alpha3,
+    #[kinded(rename = "iso4217")]
+    #[cfg_attr(not(feature = "iso_codes"), allow(dead_code))]
+    Iso4217,
+    #[kinded(rename = "bcp47")]
+    #[cfg_attr(not(feature = "iso_codes"), allow(dead_code))]
+    Bcp47,
     Predicate(TypedCustomFunction),
-    #[cfg_attr(not(feature = "regex"), allow(dead_code))]
-    Regex(RegexDef),
+    #[cfg_attr(not(any(feature = "regex", feature = "regex_lite")), allow(dead_code))]
+    Regex(RegexRule),
+    #[cfg_attr(not(any(feature = "regex", feature = "regex_lite")), allow(dead_code))]
+    NotRegex(RegexRule),
 }
 
-#[cfg_attr(not(feature = "regex"), allow(dead_code))]
-#[derive(Debug)]
+impl StringValidator {
+    /// Error variant of `regex` and `not_regex` rules, e.g. `MissingDigit` or `RegexViolated`.
+    pub fn regex_error_variant(&self) -> Option<syn::Ident> {
+        let (rule, default) = match self {
+            Self::Regex(rule) => (rule, "RegexViolated"),
+            Self::NotRegex(rule) => (rule, "NotRegexViolated"),
+            _ => return None,
+        };
+        let variant = rule
+            .variant
+            .clone()
+            .unwrap_or_else(|| syn::Ident::new(default, proc_macro2::Span::call_site()));
+        Some(variant)
+    }
+
+    /// Module and name of the function within `nutype` crate, that checks the value,
+    /// if the validator is defined by the crate (e.g. `email` or `iso4217`).
+    pub fn builtin_check_fn(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Email => Some(("formats", "is_email")),
+            Self::Url => Some(("formats", "is_url")),
+            Self::Uuid => Some(("formats", "is_uuid")),
+            Self::Ipv4 => Some(("formats", "is_ipv4")),
+            Self::Ipv6 => Some(("formats", "is_ipv6")),
+            Self::Hex => Some(("formats", "is_hex")),
+            Self::Base64 => Some(("formats", "is_base64")),
+            Self::Semver => Some(("formats", "is_semver")),
+            Self::Iso8601Date => Some(("formats", "is_iso8601_date")),
+            Self::Luhn => Some(("checksums", "is_luhn")),
+            Self::Iban => Some(("checksums", "is_iban")),
+            Self::Isbn => Some(("checksums", "is_isbn")),
+            Self::Ean => Some(("checksums", "is_ean")),
+            Self::Iso3166Alpha2 => Some(("iso_codes", "is_iso3166_alpha2")),
+            Self::Iso3166Alpha3 => Some(("iso_codes", "is_iso3166_alpha3")),
+            Self::Iso4217 => Some(("iso_codes", "is_iso4217")),
+            Self::Bcp47 => Some(("iso_codes", "is_bcp47")),
+            _ => None,
+        }
+    }
+}
+
+/// Name of the associated constant generated for a value of `one_of` validator,
+/// e.g. `"en-US"` becomes `EN_US`.
+/// Returns `None` if the value has no ASCII letters or digits, so it gets no constant.
+pub fn one_of_const_name(value: &str) -> Option<String> {
+    let mut name = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_uppercase());
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    while name.ends_with('_') {
+        name.pop();
+    }
+    if name.is_empty() {
+        return None;
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    Some(name)
+}
+
+/// A `regex` or `not_regex` rule, e.g. `regex(pattern = "[0-9]", variant = MissingDigit)`.
+#[cfg_attr(not(any(feature = "regex", feature = "regex_lite")), allow(dead_code))]
+#[derive(Debug, Clone)]
+pub struct RegexRule {
+    pub def: RegexDef,
+
+    /// Custom error variant of the rule. If not set, `RegexViolated` or `NotRegexViolated`
+    /// is used.
+    pub variant: Option<syn::Ident>,
+
+    /// The regex is compiled to a dense DFA at build time, e.g.
+    ///     regex(pattern = "^[0-9]{9}$", dfa)
+    /// Available only for string literals with `regex_dfa` feature.
+    pub dfa: bool,
+
+    /// The regex matches the bytes of the value with `regex::bytes` engine, e.g.
+    ///     regex(pattern = "^(?-u:[\x00-\x7F])+$", bytes)
+    /// Available only with `regex` feature.
+    pub bytes: bool,
+
+    /// The named capture groups of the regex are turned into accessor methods, e.g.
+    ///     regex(pattern = "^(?<area>[0-9]{3})-[0-9]{4}$", captures)
+    /// Available only for string literals of `regex` rules.
+    pub captures: bool,
+}
+
+#[cfg_attr(not(any(feature = "regex", feature = "regex_lite")), allow(dead_code))]
+#[derive(Debug, Clone)]
 pub enum RegexDef {
     /// The case, when regex is defined with string literal inlined, e.g.:
     ///     regex = "^[0-9]{9}$"
@@ -9,16 +9,19 @@ use crate::{
     string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator},
 };
 use cfg_if::cfg_if;
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use syn::{
-    LitStr, Path, Token,
+    Ident, LitChar, LitStr, Path, Token, parenthesized,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
     spanned::Spanned,
+    token::Paren,
 };
 
 use super::{
     models::{
-        RegexDef, SpannedStringSanitizer, SpannedStringValidator, StringSanitizerKind,
+        RegexDef, RegexRule, SpannedStringSanitizer, SpannedStringValidator, StringSanitizerKind,
         StringValidatorKind,
     },
     validate::validate_string_guard,
@@ -68,6 +71,14 @@ impl Parse for SpannedStringSanitizer {
                 item: StringSanitizer::Trim,
                 span: ident.span(),
             }),
+            StringSanitizerKind::TrimStart => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::TrimStart,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::TrimEnd => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::TrimEnd,
+                span: ident.span(),
+            }),
             StringSanitizerKind::Lowercase => Ok(SpannedStringSanitizer {
                 item: StringSanitizer::Lowercase,
                 span: ident.span(),
@@ -76,6 +87,157 @@ impl Parse for SpannedStringSanitizer {
                 item: StringSanitizer::Uppercase,
                 span: ident.span(),
             }),
+            StringSanitizerKind::RemoveWhitespace => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::RemoveWhitespace,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::CollapseWhitespace => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::CollapseWhitespace,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::StripControlChars => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::StripControlChars,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::Nfc
+            | StringSanitizerKind::Nfd
+            | StringSanitizerKind::Nfkc
+            | StringSanitizerKind::Nfkd
+            | StringSanitizerKind::Casefold => {
+                cfg_if! {
+                    if #[cfg(feature = "unicode")] {
+                        let item = match kind {
+                            StringSanitizerKind::Nfc => StringSanitizer::Nfc,
+                            StringSanitizerKind::Nfd => StringSanitizer::Nfd,
+                            StringSanitizerKind::Nfkc => StringSanitizer::Nfkc,
+                            StringSanitizerKind::Nfkd => StringSanitizer::Nfkd,
+                            _ => StringSanitizer::Casefold,
+                        };
+                        Ok(SpannedStringSanitizer {
+                            item,
+                            span: ident.span(),
+                        })
+                    } else {
+                        let msg = format!(
+                            "To use `{kind}` sanitizer, the feature `unicode` of the crate `nutype` must be enabled."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
+            StringSanitizerKind::Slugify => {
+                cfg_if! {
+                    if #[cfg(feature = "unicode")] {
+                        let (separator, span) = parse_slug_separator(input, &ident)?;
+                        Ok(SpannedStringSanitizer {
+                            item: StringSanitizer::Slugify { separator },
+                            span,
+                        })
+                    } else {
+                        let msg = format!(
+                            "To use `{kind}` sanitizer, the feature `unicode` of the crate `nutype` must be enabled."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
+            StringSanitizerKind::Truncate => {
+                if input.peek(Token![=]) {
+                    let _: Token![=] = input.parse()?;
+                    let (len, span) = parse_number_or_expr::<usize>(input)?;
+                    return Ok(SpannedStringSanitizer {
+                        item: StringSanitizer::Truncate {
+                            len,
+                            graphemes: false,
+                        },
+                        span,
+                    });
+                }
+                let mut len = None;
+                #[cfg_attr(not(feature = "unicode"), allow(unused_mut))]
+                let mut graphemes = false;
+                let span = parse_sanitizer_args(input, &ident, |name, content| {
+                    if name == "len" {
+                        let _: Token![=] = content.parse()?;
+                        len = Some(parse_number_or_expr::<usize>(content)?.0);
+                    } else if name == "graphemes" {
+                        cfg_if! {
+                            if #[cfg(feature = "unicode")] {
+                                graphemes = true;
+                            } else {
+                                let msg = "To truncate by grapheme clusters, the feature `unicode` of the crate `nutype` must be enabled.";
+                                return Err(syn::Error::new(name.span(), msg));
+                            }
+                        }
+                    } else {
+                        let msg = format!(
+                            "Unknown argument `{name}` of `truncate` sanitizer.\nExpected `len` and optionally `graphemes`."
+                        );
+                        return Err(syn::Error::new(name.span(), msg));
+                    }
+                    Ok(())
+                })?;
+                let Some(len) = len else {
+                    let msg = "`truncate` sanitizer requires the length, e.g. `truncate = 255` or `truncate(len = 255, graphemes)`.";
+                    return Err(syn::Error::new(span, msg));
+                };
+                Ok(SpannedStringSanitizer {
+                    item: StringSanitizer::Truncate { len, graphemes },
+                    span,
+                })
+            }
+            StringSanitizerKind::Replace => {
+                let mut from = None;
+                let mut to = None;
+                let span = parse_sanitizer_args(input, &ident, |name, content| {
+                    let _: Token![=] = content.parse()?;
+                    if name == "from" {
+                        from = Some(content.parse::<LitStr>()?);
+                    } else if name == "to" {
+                        to = Some(content.parse::<LitStr>()?);
+                    } else {
+                        let msg = format!(
+                            "Unknown argument `{name}` of `replace` sanitizer.\nExpected `from` and `to`."
+                        );
+                        return Err(syn::Error::new(name.span(), msg));
+                    }
+                    Ok(())
+                })?;
+                let (Some(from), Some(to)) = (from, to) else {
+                    let msg = "`replace` sanitizer requires `from` and `to` strings, e.g. `replace(from = \"\\t\", to = \" \")`.";
+                    return Err(syn::Error::new(span, msg));
+                };
+                Ok(SpannedStringSanitizer {
+                    item: StringSanitizer::Replace { from, to },
+                    span,
+                })
+            }
+            StringSanitizerKind::PadStart => {
+                let mut len = None;
+                let mut fill = None;
+                let span = parse_sanitizer_args(input, &ident, |name, content| {
+                    let _: Token![=] = content.parse()?;
+                    if name == "len" {
+                        len = Some(parse_number_or_expr::<usize>(content)?.0);
+                    } else if name == "char" {
+                        fill = Some(content.parse::<LitChar>()?);
+                    } else {
+                        let msg = format!(
+                            "Unknown argument `{name}` of `pad_start` sanitizer.\nExpected `len` and `char`."
+                        );
+                        return Err(syn::Error::new(name.span(), msg));
+                    }
+                    Ok(())
+                })?;
+                let (Some(len), Some(fill)) = (len, fill) else {
+                    let msg = "`pad_start` sanitizer requires `len` and `char`, e.g. `pad_start(len = 10, char = '0')`.";
+                    return Err(syn::Error::new(span, msg));
+                };
+                Ok(SpannedStringSanitizer {
+                    item: StringSanitizer::PadStart { len, fill },
+                    span,
+                })
+            }
             StringSanitizerKind::With => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) =
@@ -89,6 +251,58 @@ impl Parse for SpannedStringSanitizer {
     }
 }
 
+/// Parses the arguments of a sanitizer given in parentheses, e.g. `(from = "\t", to = " ")`.
+/// `parse_arg` is called with the name of every argument and must parse the rest of it.
+fn parse_sanitizer_args(
+    input: ParseStream,
+    sanitizer: &Ident,
+    mut parse_arg: impl FnMut(&Ident, ParseStream) -> syn::Result<()>,
+) -> syn::Result<Span> {
+    if !input.peek(Paren) {
+        let msg = format!("`{sanitizer}` sanitizer must be used with parenthesis.");
+        return Err(syn::Error::new(sanitizer.span(), msg));
+    }
+    let content;
+    let parens = parenthesized!(content in input);
+    while !content.is_empty() {
+        let name: Ident = content.parse()?;
+        parse_arg(&name, &content)?;
+        if !content.is_empty() {
+            let _: Token![,] = content.parse()?;
+        }
+    }
+    Ok(parens.span.join())
+}
+
+/// Parses the optional separator of `slug` validator and `slugify` sanitizer,
+/// e.g. `slug(separator = '_')`. The default separator is `-`.
+fn parse_slug_separator(input: ParseStream, ident: &Ident) -> syn::Result<(char, Span)> {
+    if !input.peek(Paren) {
+        return Ok(('-', ident.span()));
+    }
+    let content;
+    let parens = parenthesized!(content in input);
+    let name: Ident = content.parse()?;
+    if name != "separator" {
+        let msg = format!("Unknown argument `{name}` of `{ident}`.\nExpected `separator`.");
+        return Err(syn::Error::new(name.span(), msg));
+    }
+    let _: Token![=] = content.parse()?;
+    let lit: LitChar = content.parse()?;
+    let _: Option<Token![,]> = content.parse()?;
+    if !content.is_empty() {
+        return Err(content.error("Unexpected tokens after the separator."));
+    }
+    let separator = lit.value();
+    if !separator.is_ascii_punctuation() {
+        let msg = format!(
+            "The separator of `{ident}` must be an ASCII punctuation char, e.g. '-' or '_'.\nGot {separator:?}."
+        );
+        return Err(syn::Error::new(lit.span(), msg));
+    }
+    Ok((separator, parens.span.join()))
+}
+
 impl Parse for SpannedStringValidator {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let (kind, ident) = parse_validator_kind(input)?;
@@ -126,10 +340,185 @@ impl Parse for SpannedStringValidator {
                     span,
                 })
             }
+            StringValidatorKind::LenBytesMin => {
+                let _: Token![=] = input.parse()?;
+                let (min_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::LenBytesMin(min_len),
+                    span,
+                })
+            }
+            StringValidatorKind::LenBytesMax => {
+                let _: Token![=] = input.parse()?;
+                let (max_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::LenBytesMax(max_len),
+                    span,
+                })
+            }
+            StringValidatorKind::LenGraphemeMin | StringValidatorKind::LenGraphemeMax => {
+                cfg_if! {
+                    if #[cfg(feature = "unicode")] {
+                        let _: Token![=] = input.parse()?;
+                        let (len, span) = parse_number_or_expr::<usize>(input)?;
+                        let item = if kind == StringValidatorKind::LenGraphemeMin {
+                            StringValidator::LenGraphemeMin(len)
+                        } else {
+                            StringValidator::LenGraphemeMax(len)
+                        };
+                        Ok(SpannedStringValidator { item, span })
+                    } else {
+                        let msg = format!(
+                            "To use `{kind}` validator, the feature `unicode` of the crate `nutype` must be enabled."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
             StringValidatorKind::NotEmpty => Ok(SpannedStringValidator {
                 item: StringValidator::NotEmpty,
                 span: ident.span(),
             }),
+            StringValidatorKind::Ascii => Ok(SpannedStringValidator {
+                item: StringValidator::Ascii,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Alphanumeric => Ok(SpannedStringValidator {
+                item: StringValidator::Alphanumeric,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Printable => Ok(SpannedStringValidator {
+                item: StringValidator::Printable,
+                span: ident.span(),
+            }),
+            StringValidatorKind::NoControlChars => Ok(SpannedStringValidator {
+                item: StringValidator::NoControlChars,
+                span: ident.span(),
+            }),
+            StringValidatorKind::StartsWith => {
+                let (lit, span) = parse_str_literal(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::StartsWith(lit),
+                    span,
+                })
+            }
+            StringValidatorKind::EndsWith => {
+                let (lit, span) = parse_str_literal(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::EndsWith(lit),
+                    span,
+                })
+            }
+            StringValidatorKind::Contains => {
+                let (lit, span) = parse_str_literal(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::Contains(lit),
+                    span,
+                })
+            }
+            StringValidatorKind::NotContains => {
+                let (lit, span) = parse_str_literal(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::NotContains(lit),
+                    span,
+                })
+            }
+            StringValidatorKind::OneOf => {
+                let _: Token![=] = input.parse()?;
+                let content;
+                let brackets = syn::bracketed!(content in input);
+                let values: Punctuated<LitStr, Token![,]> =
+                    content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::OneOf(values.into_iter().collect()),
+                    span: brackets.span.join(),
+                })
+            }
+            StringValidatorKind::Slug => {
+                let (separator, span) = parse_slug_separator(input, &ident)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::Slug { separator },
+                    span,
+                })
+            }
+            StringValidatorKind::Identifier => Ok(SpannedStringValidator {
+                item: StringValidator::Identifier,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Email
+            | StringValidatorKind::Url
+            | StringValidatorKind::Uuid
+            | StringValidatorKind::Ipv4
+            | StringValidatorKind::Ipv6
+            | StringValidatorKind::Hex
+            | StringValidatorKind::Base64
+            | StringValidatorKind::Semver
+            | StringValidatorKind::Iso8601Date => {
+                cfg_if! {
+                    if #[cfg(feature = "formats")] {
+                        let item = match kind {
+                            StringValidatorKind::Email => StringValidator::Email,
+                            StringValidatorKind::Url => StringValidator::Url,
+                            StringValidatorKind::Uuid => StringValidator::Uuid,
+                            StringValidatorKind::Ipv4 => StringValidator::Ipv4,
+                            StringValidatorKind::Ipv6 => StringValidator::Ipv6,
+                            StringValidatorKind::Hex => StringValidator::Hex,
+                            StringValidatorKind::Base64 => StringValidator::Base64,
+                            StringValidatorKind::Semver => StringValidator::Semver,
+                            _ => StringValidator::Iso8601Date,
+                        };
+                        Ok(SpannedStringValidator {
+                            item,
+                            span: ident.span(),
+                        })
+                    } else {
+                        let msg = format!(
+                            "To use `{kind}` validator, the feature `formats` of the crate `nutype` must be enabled."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
+            StringValidatorKind::Luhn => Ok(SpannedStringValidator {
+                item: StringValidator::Luhn,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Iban => Ok(SpannedStringValidator {
+                item: StringValidator::Iban,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Isbn => Ok(SpannedStringValidator {
+                item: StringValidator::Isbn,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Ean => Ok(SpannedStringValidator {
+                item: StringValidator::Ean,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Iso3166Alpha2
+            | StringValidatorKind::Iso3166Alpha3
+            | StringValidatorKind::Iso4217
+            | StringValidatorKind::Bcp47 => {
+                cfg_if! {
+                    if #[cfg(feature = "iso_codes")] {
+                        let item = match kind {
+                            StringValidatorKind::Iso3166Alpha2 => StringValidator::Iso3166Alpha2,
+                            StringValidatorKind::Iso3166Alpha3 => StringValidator::Iso3166Alpha3,
+                            StringValidatorKind::Iso4217 => StringValidator::Iso4217,
+                            _ => StringValidator::Bcp47,
+                        };
+                        Ok(SpannedStringValidator {
+                            item,
+                            span: ident.span(),
+                        })
+                    } else {
+                        let msg = format!(
+                            "To use `{kind}` validator, the feature `iso_codes` of the crate `nutype` must be enabled."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
             StringValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) = parse_typed_custom_function_raw(input, "&str")?;
@@ -138,22 +527,19 @@ impl Parse for SpannedStringValidator {
                     span,
                 })
             }
-            StringValidatorKind::Regex => {
+            StringValidatorKind::Regex | StringValidatorKind::NotRegex => {
                 cfg_if! {
-                    if #[cfg(feature = "regex")] {
-                        let _eq: Token![=] = input.parse()?;
-                        let SpannedRegexDef {
-                            item: regex_def,
-                            span,
-                        } = input.parse()?;
-                        Ok(SpannedStringValidator {
-                            item: StringValidator::Regex(regex_def),
-                            span
-                        })
+                    if #[cfg(any(feature = "regex", feature = "regex_lite"))] {
+                        let (rule, span) = parse_regex_rule(input, &ident)?;
+                        let item = if kind == StringValidatorKind::Regex {
+                            StringValidator::Regex(rule)
+                        } else {
+                            StringValidator::NotRegex(rule)
+                        };
+                        Ok(SpannedStringValidator { item, span })
                     } else {
                         let msg = concat!(
-                            "To validate string types with regex, the feature `regex` of the crate `nutype` must be enabled.\n",
-                            "IMPORTANT: Make sure that your crate EXPLICITLY depends on the `regex` crate.\n",
+                            "To validate string types with regex, the feature `regex` (or `regex_lite`) of the crate `nutype` must be enabled.\n",
                             "And... don't forget to take care of yourself and your beloved ones. That is even more important.",
                         );
                         Err(syn::Error::new(ident.span(), msg))
@@ -164,6 +550,123 @@ impl Parse for SpannedStringValidator {
     }
 }
 
+/// Parses `= "literal"` part of validators like `starts_with = "foo"`.
+fn parse_str_literal(input: ParseStream) -> syn::Result<(LitStr, proc_macro2::Span)> {
+    let _: Token![=] = input.parse()?;
+    let lit: LitStr = input.parse()?;
+    let span = lit.span();
+    Ok((lit, span))
+}
+
+/// Parses `regex` and `not_regex` rules, which are given either as `regex = "[0-9]"`
+/// or with a custom error variant as `regex(pattern = "[0-9]", variant = MissingDigit)`.
+/// `dfa` flag compiles a string literal to a DFA at build time: `regex(pattern = "[0-9]", dfa)`.
+/// `bytes` flag matches the value with `regex::bytes` engine: `regex(pattern = "(?-u:\xFF)", bytes)`.
+/// `captures` flag generates accessors of the named groups: `regex(pattern = "(?<d>[0-9])", captures)`.
+#[cfg_attr(not(any(feature = "regex", feature = "regex_lite")), allow(dead_code))]
+fn parse_regex_rule(input: ParseStream, ident: &Ident) -> syn::Result<(RegexRule, Span)> {
+    if !input.peek(Paren) {
+        let _eq: Token![=] = input.parse()?;
+        let SpannedRegexDef { item: def, span } = input.parse()?;
+        let rule = RegexRule {
+            def,
+            variant: None,
+            dfa: false,
+            bytes: false,
+            captures: false,
+        };
+        return Ok((rule, span));
+    }
+    let mut def = None;
+    let mut variant = None;
+    let mut dfa = false;
+    let mut bytes = false;
+    let mut captures = false;
+    let content;
+    let parens = parenthesized!(content in input);
+    while !content.is_empty() {
+        let name: Ident = content.parse()?;
+        if name == "dfa" {
+            if !cfg!(feature = "regex_dfa") {
+                let msg = format!(
+                    "To use `dfa` option of `{ident}`, the feature `regex_dfa` of the crate `nutype` must be enabled."
+                );
+                return Err(syn::Error::new(name.span(), msg));
+            }
+            dfa = true;
+        } else if name == "bytes" {
+            if !cfg!(feature = "regex") {
+                let msg = format!(
+                    "To use `bytes` option of `{ident}`, the feature `regex` of the crate `nutype` must be enabled."
+                );
+                return Err(syn::Error::new(name.span(), msg));
+            }
+            bytes = true;
+        } else if name == "captures" {
+            captures = true;
+        } else {
+            let _: Token![=] = content.parse()?;
+            if name == "pattern" {
+                def = Some(content.parse::<SpannedRegexDef>()?.item);
+            } else if name == "variant" {
+                variant = Some(content.parse::<Ident>()?);
+            } else {
+                let msg = format!(
+                    "Unknown argument `{name}` of `{ident}`.\nExpected `pattern` and optionally `variant`, `dfa`, `bytes` and `captures`."
+                );
+                return Err(syn::Error::new(name.span(), msg));
+            }
+        }
+        if !content.is_empty() {
+            let _: Token![,] = content.parse()?;
+        }
+    }
+    let Some(def) = def else {
+        let msg = format!(
+            "`{ident}` requires a pattern, e.g. `{ident}(pattern = \"[0-9]\", variant = MissingDigit)`."
+        );
+        return Err(syn::Error::new(parens.span.join(), msg));
+    };
+    if dfa && matches!(def, RegexDef::Path(_)) {
+        let msg = format!(
+            "`dfa` option of `{ident}` requires the pattern to be a string literal, so it can be compiled at build time."
+        );
+        return Err(syn::Error::new(parens.span.join(), msg));
+    }
+    if captures {
+        let maybe_msg = if ident != "regex" {
+            Some(format!(
+                "`captures` option is not available for `{ident}`, since a valid value never matches it."
+            ))
+        } else if matches!(def, RegexDef::Path(_)) {
+            Some(format!(
+                "`captures` option of `{ident}` requires the pattern to be a string literal, so the groups are known at build time."
+            ))
+        } else if dfa {
+            Some(format!(
+                "`captures` option of `{ident}` cannot be combined with `dfa`, since a DFA does not track the capture groups."
+            ))
+        } else if bytes {
+            Some(format!(
+                "`captures` option of `{ident}` cannot be combined with `bytes`, since the accessors return `&str`."
+            ))
+        } else {
+            None
+        };
+        if let Some(msg) = maybe_msg {
+            return Err(syn::Error::new(parens.span.join(), msg));
+        }
+    }
+    let rule = RegexRule {
+        def,
+        variant,
+        dfa,
+        bytes,
+        captures,
+    };
+    Ok((rule, parens.span.join()))
+}
+
 type SpannedRegexDef = SpannedItem<RegexDef>;
 
 impl Parse for SpannedRegexDef {
@@ -0,0 +1,113 @@
+//! Conversion of regex literals into `nutype::__private::regex_arbitrary::Node`, so
+//! `derive(Arbitrary)` can generate values, that match the regex.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use regex_syntax::{
+    ParserBuilder,
+    hir::{Class, Hir, HirKind, Look},
+};
+
+/// Returns an expression of type `Node` or the reason, why the regex is not supported.
+pub fn gen_regex_node(pattern: &str) -> Result<TokenStream, String> {
+    // UTF-8 mode is disabled to accept the patterns of `regex::bytes` too.
+    let hir = ParserBuilder::new()
+        .utf8(false)
+        .build()
+        .parse(pattern)
+        .map_err(|err| err.to_string())?;
+    gen_node(&hir)
+}
+
+fn gen_node(hir: &Hir) -> Result<TokenStream, String> {
+    let node = quote!(::nutype::__private::regex_arbitrary::Node);
+    let tokens = match hir.kind() {
+        HirKind::Empty => quote!(#node::Literal("")),
+        HirKind::Literal(literal) => {
+            let literal = core::str::from_utf8(&literal.0)
+                .map_err(|_| "it matches bytes, that are not valid UTF-8".to_string())?;
+            quote!(#node::Literal(#literal))
+        }
+        HirKind::Class(class) => {
+            let ranges: Vec<(char, char)> = match class {
+                Class::Unicode(class) => class.iter().map(|r| (r.start(), r.end())).collect(),
+                Class::Bytes(class) => class
+                    .iter()
+                    .map(|r| {
+                        if r.end().is_ascii() {
+                            Ok((char::from(r.start()), char::from(r.end())))
+                        } else {
+                            Err("it matches bytes, that are not valid UTF-8".to_string())
+                        }
+                    })
+                    .collect::<Result<_, _>>()?,
+            };
+            if ranges.is_empty() {
+                return Err("it contains a class, that matches nothing".to_string());
+            }
+            let ranges = ranges.iter().map(|(start, end)| quote!((#start, #end)));
+            quote!(#node::Class(&[#(#ranges),*]))
+        }
+        HirKind::Look(look) => match look {
+            // The anchors are satisfied by the generated value as it is.
+            Look::Start
+            | Look::End
+            | Look::StartLF
+            | Look::EndLF
+            | Look::StartCRLF
+            | Look::EndCRLF => quote!(#node::Literal("")),
+            _ => return Err("word boundaries are not supported".to_string()),
+        },
+        HirKind::Repetition(repetition) => {
+            let min = repetition.min as usize;
+            let max = match repetition.max {
+                Some(max) => {
+                    let max = max as usize;
+                    quote!(::core::option::Option::Some(#max))
+                }
+                None => quote!(::core::option::Option::None),
+            };
+            let sub = gen_node(&repetition.sub)?;
+            quote!(#node::Repetition { min: #min, max: #max, sub: &#sub })
+        }
+        HirKind::Capture(capture) => gen_node(&capture.sub)?,
+        HirKind::Concat(subs) => {
+            let subs = subs.iter().map(gen_node).collect::<Result<Vec<_>, _>>()?;
+            quote!(#node::Concat(&[#(#subs),*]))
+        }
+        HirKind::Alternation(subs) => {
+            let subs = subs.iter().map(gen_node).collect::<Result<Vec<_>, _>>()?;
+            quote!(#node::Alternation(&[#(#subs),*]))
+        }
+    };
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen_regex_node_unsupported() {
+        assert_eq!(
+            gen_regex_node(r"\bword\b").unwrap_err(),
+            "word boundaries are not supported"
+        );
+        assert_eq!(
+            gen_regex_node(r"(?-u:\xFF)").unwrap_err(),
+            "it matches bytes, that are not valid UTF-8"
+        );
+        assert_eq!(
+            gen_regex_node(r"[a&&b]").unwrap_err(),
+            "it contains a class, that matches nothing"
+        );
+    }
+
+    #[test]
+    fn test_gen_regex_node() {
+        let node = gen_regex_node("^(?:ab|[0-9])+$").unwrap().to_string();
+        assert!(node.contains("Alternation"), "{node}");
+        assert!(node.contains("Repetition"), "{node}");
+        assert!(!node.contains("Capture"), "{node}");
+    }
+}
@@ -0,0 +1,130 @@
+//! Named capture groups of regex literals, that are turned into accessor methods,
+//! e.g. `fn area(&self) -> &str` for `regex(pattern = "^(?P<area>[0-9]{3})-[0-9]{4}$", captures)`.
+
+use proc_macro2::Span;
+use regex_syntax::{
+    ParserBuilder,
+    hir::{Hir, HirKind},
+};
+
+/// Methods generated by nutype, that cannot be shadowed by the accessors.
+pub const RESERVED_METHOD_NAMES: &[&str] = &[
+    "new",
+    "try_new",
+    "try_from_str",
+    "new_unchecked",
+    "into_inner",
+    "as_inner",
+    "validate",
+    "is_valid",
+    "sanitize",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureGroup {
+    pub name: String,
+
+    /// The group may not participate in a match, e.g. `(?P<ext>x[0-9]+)?`,
+    /// so the accessor returns `Option<&str>`.
+    pub is_optional: bool,
+}
+
+impl CaptureGroup {
+    /// Name of the accessor method. Keywords are turned into raw identifiers, e.g. `r#type`.
+    /// Returns `None` if the group name cannot be an identifier, e.g. `a.b` or `self`.
+    pub fn accessor_ident(&self, span: Span) -> Option<syn::Ident> {
+        if syn::parse_str::<syn::Ident>(&self.name).is_ok() {
+            Some(syn::Ident::new(&self.name, span))
+        } else if syn::parse_str::<syn::Ident>(&format!("r#{}", self.name)).is_ok() {
+            Some(syn::Ident::new_raw(&self.name, span))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the named capture groups of the pattern in order of their appearance.
+pub fn named_capture_groups(pattern: &str) -> Vec<CaptureGroup> {
+    // UTF-8 mode is disabled to accept the patterns of `regex::bytes` too.
+    // The pattern is already validated by the regex engine, so it can be parsed.
+    let Ok(hir) = ParserBuilder::new().utf8(false).build().parse(pattern) else {
+        return Vec::new();
+    };
+    let mut groups = Vec::new();
+    collect_named_capture_groups(&hir, false, &mut groups);
+    groups
+}
+
+fn collect_named_capture_groups(hir: &Hir, is_optional: bool, groups: &mut Vec<CaptureGroup>) {
+    match hir.kind() {
+        HirKind::Capture(capture) => {
+            if let Some(name) = &capture.name {
+                groups.push(CaptureGroup {
+                    name: name.to_string(),
+                    is_optional,
+                });
+            }
+            collect_named_capture_groups(&capture.sub, is_optional, groups);
+        }
+        HirKind::Repetition(repetition) => {
+            let is_optional = is_optional || repetition.min == 0;
+            collect_named_capture_groups(&repetition.sub, is_optional, groups);
+        }
+        HirKind::Concat(subs) => {
+            for sub in subs {
+                collect_named_capture_groups(sub, is_optional, groups);
+            }
+        }
+        // Only one of the branches participates in a match.
+        HirKind::Alternation(subs) => {
+            for sub in subs {
+                collect_named_capture_groups(sub, true, groups);
+            }
+        }
+        HirKind::Empty | HirKind::Literal(_) | HirKind::Class(_) | HirKind::Look(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(name: &str, is_optional: bool) -> CaptureGroup {
+        CaptureGroup {
+            name: name.to_string(),
+            is_optional,
+        }
+    }
+
+    #[test]
+    fn test_named_capture_groups() {
+        assert_eq!(
+            named_capture_groups(r"^(?P<area>[0-9]{3})-([0-9]{4})(?:x(?P<ext>[0-9]+))?$"),
+            vec![group("area", false), group("ext", true)]
+        );
+        assert_eq!(
+            named_capture_groups(r"^(?<digits>[0-9]+)|(?<letters>[a-z]+)$"),
+            vec![group("digits", true), group("letters", true)]
+        );
+        assert_eq!(
+            named_capture_groups(r"^(?<word>[a-z]+ )+(?<last>[a-z]*)$"),
+            vec![group("word", false), group("last", false)]
+        );
+        assert_eq!(named_capture_groups("^[0-9]+$"), vec![]);
+    }
+
+    #[test]
+    fn test_accessor_ident() {
+        let accessor = |name: &str| {
+            group(name, false)
+                .accessor_ident(Span::call_site())
+                .map(|ident| ident.to_string())
+        };
+        assert_eq!(accessor("area"), Some("area".to_string()));
+        assert_eq!(accessor("type"), Some("r#type".to_string()));
+        assert_eq!(accessor("match"), Some("r#match".to_string()));
+        assert_eq!(accessor("self"), None);
+        assert_eq!(accessor("a.b"), None);
+        assert_eq!(accessor("a[0]"), None);
+    }
+}
@@ -0,0 +1,35 @@
+//! Regexes compiled to a dense DFA at build time with `regex(pattern = "...", dfa)`.
+//! The DFA is serialized and embedded into the generated code, so there is no runtime
+//! compilation and no lazy static.
+
+use regex_automata::{
+    dfa::dense::{Builder, Config},
+    nfa::thompson,
+    util::syntax,
+};
+
+/// Serialized DFA without the initial padding, so it can be embedded at an aligned address.
+pub struct SerializedDfa {
+    pub little_endian: Vec<u8>,
+    pub big_endian: Vec<u8>,
+}
+
+/// Builds a minimized DFA (to keep the embedded tables small) and serializes it in both
+/// byte orders, since the target of the compilation is not known to the macro.
+/// With `bytes` the pattern may match invalid UTF-8, as `regex::bytes` does.
+pub fn build_serialized_dfa(pattern: &str, bytes: bool) -> Result<SerializedDfa, String> {
+    let dfa = Builder::new()
+        .configure(Config::new().minimize(true))
+        .syntax(syntax::Config::new().utf8(!bytes))
+        .thompson(thompson::Config::new().utf8(!bytes))
+        .build(pattern)
+        .map_err(|err| format!("Failed to compile the regex to a DFA: {err}"))?;
+    let (little_endian, pad) = dfa.to_bytes_little_endian();
+    let little_endian = little_endian[pad..].to_vec();
+    let (big_endian, pad) = dfa.to_bytes_big_endian();
+    let big_endian = big_endian[pad..].to_vec();
+    Ok(SerializedDfa {
+        little_endian,
+        big_endian,
+    })
+}
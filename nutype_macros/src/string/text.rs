@@ -0,0 +1,66 @@
+//! Checks and transformations of string literals, that mirror the helpers from
+//! `nutype::__private` used by the generated code.
+//!
+//! The module has no dependencies within the crate, so `test_suite` includes it
+//! to check that both implementations agree.
+
+/// Replaces every run of whitespaces with a single space, the same way as
+/// `collapse_whitespace` sanitizer does.
+pub fn collapse_whitespace(value: &str) -> String {
+    let mut collapsed = String::with_capacity(value.len());
+    let mut prev_is_whitespace = false;
+    for c in value.chars() {
+        if !c.is_whitespace() {
+            collapsed.push(c);
+        } else if !prev_is_whitespace {
+            collapsed.push(' ');
+        }
+        prev_is_whitespace = c.is_whitespace();
+    }
+    collapsed
+}
+
+/// Transliterates, lowercases and joins the words the same way as `slugify` sanitizer,
+/// but the separators at the edges are kept, so a part of a value can be checked.
+#[cfg(feature = "unicode")]
+pub fn slugify_keeping_edges(value: &str, separator: char) -> String {
+    let mut slug = String::with_capacity(value.len());
+    let mut is_word_boundary = false;
+    for c in value.chars() {
+        let ascii = deunicode::deunicode_char(c).unwrap_or(" ");
+        for b in ascii.bytes() {
+            if b.is_ascii_alphanumeric() {
+                if is_word_boundary {
+                    slug.push(separator);
+                }
+                is_word_boundary = false;
+                slug.push(char::from(b.to_ascii_lowercase()));
+            } else {
+                is_word_boundary = true;
+            }
+        }
+    }
+    if is_word_boundary {
+        slug.push(separator);
+    }
+    slug
+}
+
+pub fn is_slug(value: &str, separator: char) -> bool {
+    value.split(separator).all(|word| {
+        !word.is_empty()
+            && word
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+    })
+}
+
+pub fn is_identifier(value: &str) -> bool {
+    let mut bytes = value.bytes();
+    matches!(bytes.next(), Some(b) if b.is_ascii_alphabetic() || b == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+pub fn is_control_or_zero_width(c: char) -> bool {
+    c.is_control() || matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}')
+}
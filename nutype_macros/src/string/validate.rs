@@ -1,6 +1,8 @@
 use kinded::Kinded;
 
 use proc_macro2::Span;
+#[cfg(feature = "unicode")]
+use unicode_normalization::UnicodeNormalization;
 
 use crate::{
     common::{
@@ -14,28 +16,49 @@ use crate::{
 
 use super::models::{
     SpannedStringSanitizer, SpannedStringValidator, StringDeriveTrait, StringSanitizerKind,
+    StringValidatorKind, one_of_const_name,
 };
+#[cfg(feature = "unicode")]
+use super::text::slugify_keeping_edges;
+use super::text::{collapse_whitespace, is_control_or_zero_width, is_identifier, is_slug};
 
 pub fn validate_string_guard(
     raw_guard: StringRawGuard,
     type_name: &TypeName,
 ) -> Result<StringGuard, syn::Error> {
+    // Validators (e.g. `one_of`) are checked against the sanitizers,
+    // which are consumed before the validators.
+    let sanitizers: Vec<StringSanitizer> = raw_guard
+        .sanitizers
+        .iter()
+        .map(|s| s.item.clone())
+        .collect();
     validate_guard(
         raw_guard,
         type_name,
-        validate_validators,
+        |validators| validate_validators(validators, &sanitizers),
         validate_sanitizers,
     )
 }
 
 fn validate_validators(
     validators: Vec<SpannedStringValidator>,
+    sanitizers: &[StringSanitizer],
 ) -> Result<Vec<StringValidator>, syn::Error> {
+    let sanitizer_kinds: Vec<StringSanitizerKind> = sanitizers.iter().map(|s| s.kind()).collect();
+
     // Check duplicates
     //
-    validate_duplicates(&validators, |kind| {
+    // `regex` and `not_regex` can be used multiple times with different error variants.
+    let unique_validators: Vec<SpannedStringValidator> = validators
+        .iter()
+        .filter(|v| v.item.regex_error_variant().is_none())
+        .cloned()
+        .collect();
+    validate_duplicates(&unique_validators, |kind| {
         format!("Duplicated validators `{kind}`.\nDon't worry, you still remain ingenious!")
     })?;
+    validate_regex_error_variants(&validators)?;
 
     // len_char_max VS len_char_min
     //
@@ -89,44 +112,622 @@ fn validate_validators(
         return Err(err);
     }
 
+    // len_bytes_max VS len_bytes_min
+    //
+    let maybe_len_bytes_min = validators
+        .iter()
+        .flat_map(|v| match v.item {
+            StringValidator::LenBytesMin(ValueOrExpr::Value(len)) => Some((v.span, len)),
+            _ => None,
+        })
+        .next();
+    let maybe_len_bytes_max = validators
+        .iter()
+        .flat_map(|v| match v.item {
+            StringValidator::LenBytesMax(ValueOrExpr::Value(len)) => Some((v.span, len)),
+            _ => None,
+        })
+        .next();
+    if let (Some((_, len_bytes_min)), Some((len_bytes_max_span, len_bytes_max))) =
+        (maybe_len_bytes_min, maybe_len_bytes_max)
+        && len_bytes_min > len_bytes_max
+    {
+        let msg =
+            "`len_bytes_min` cannot be greater than `len_bytes_max`.\nDon't you find this obvious?";
+        let err = syn::Error::new(len_bytes_max_span, msg);
+        return Err(err);
+    }
+
+    // len_grapheme_max VS len_grapheme_min
+    //
+    let maybe_len_grapheme_min = validators
+        .iter()
+        .flat_map(|v| match v.item {
+            StringValidator::LenGraphemeMin(ValueOrExpr::Value(len)) => Some((v.span, len)),
+            _ => None,
+        })
+        .next();
+    let maybe_len_grapheme_max = validators
+        .iter()
+        .flat_map(|v| match v.item {
+            StringValidator::LenGraphemeMax(ValueOrExpr::Value(len)) => Some((v.span, len)),
+            _ => None,
+        })
+        .next();
+    if let (Some((_, len_grapheme_min)), Some((len_grapheme_max_span, len_grapheme_max))) =
+        (maybe_len_grapheme_min, maybe_len_grapheme_max)
+        && len_grapheme_min > len_grapheme_max
+    {
+        let msg = "`len_grapheme_min` cannot be greater than `len_grapheme_max`.\nDon't you find this obvious?";
+        let err = syn::Error::new(len_grapheme_max_span, msg);
+        return Err(err);
+    }
+
+    validate_length_sanitizers(&validators, sanitizers)?;
+    validate_text_validators(&validators, sanitizers)?;
+
+    // Uppercase codes VS lowercase
+    //
+    let maybe_lowercase = sanitizer_kinds.iter().find(|kind| {
+        matches!(
+            kind,
+            StringSanitizerKind::Lowercase | StringSanitizerKind::Casefold
+        )
+    });
+    let maybe_code_validator = validators.iter().find(|v| {
+        matches!(
+            v.item,
+            StringValidator::Iso3166Alpha2
+                | StringValidator::Iso3166Alpha3
+                | StringValidator::Iso4217
+        )
+    });
+    if let (Some(lowercase), Some(v)) = (maybe_lowercase, maybe_code_validator) {
+        if !sanitizer_kinds.contains(&StringSanitizerKind::With) {
+            let msg = format!(
+                "`{}` accepts only uppercase codes, so it contradicts `{lowercase}` sanitizer.\nConsider using `uppercase` sanitizer instead.",
+                v.kind()
+            );
+            return Err(syn::Error::new(v.span, msg));
+        }
+    }
+
+    // slug VS slugify and case sanitizers
+    //
+    let maybe_slug = validators.iter().find_map(|v| match v.item {
+        StringValidator::Slug { separator } => Some((v, separator)),
+        _ => None,
+    });
+    if let Some((v, separator)) = maybe_slug {
+        if !sanitizer_kinds.contains(&StringSanitizerKind::With) {
+            if let Some(slugify_separator) = slugify_separator(sanitizers) {
+                if slugify_separator != separator {
+                    let msg = format!(
+                        "`{}` contradicts `slugify` sanitizer, which joins the words with {slugify_separator:?}.\nUse the same separator for both.",
+                        describe_validator(&v.item),
+                    );
+                    return Err(syn::Error::new(v.span, msg));
+                }
+            }
+            if sanitizer_kinds.contains(&StringSanitizerKind::Uppercase) {
+                let msg = "`slug` accepts only lowercase letters, so it contradicts `uppercase` sanitizer.\nConsider using `lowercase` or `slugify` sanitizer instead.";
+                return Err(syn::Error::new(v.span, msg));
+            }
+        }
+    }
+
     // Validate regex
     //
-    #[cfg(feature = "regex")]
+    #[cfg(any(feature = "regex", feature = "regex_lite"))]
     for v in validators.iter() {
-        if let StringValidator::Regex(ref regex_def) = v.item {
-            regex_validation::validate_regex_def(regex_def, v.span)?;
+        if let StringValidator::Regex(ref rule) | StringValidator::NotRegex(ref rule) = v.item {
+            regex_validation::validate_regex_rule(rule, v.span)?;
         }
     }
+    #[cfg(any(feature = "regex", feature = "regex_lite"))]
+    regex_validation::validate_capture_groups(&validators)?;
 
     let validators: Vec<StringValidator> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
 
+/// Every `regex` and `not_regex` rule must have its own error variant, that does not clash
+/// with the variants of the other validators (e.g. `NotEmptyViolated`).
+fn validate_regex_error_variants(validators: &[SpannedStringValidator]) -> Result<(), syn::Error> {
+    let mut seen_variants: Vec<String> = validators
+        .iter()
+        .filter(|v| v.item.regex_error_variant().is_none())
+        .map(|v| format!("{:?}Violated", v.kind()))
+        .collect();
+    for v in validators {
+        let Some(variant) = v.item.regex_error_variant() else {
+            continue;
+        };
+        let variant = variant.to_string();
+        if seen_variants.contains(&variant) {
+            let kind = v.kind();
+            let msg = format!(
+                "Duplicated error variant `{variant}`.\nGive every `{kind}` rule its own variant, e.g. `{kind}(pattern = \"[0-9]\", variant = MissingDigit)`."
+            );
+            return Err(syn::Error::new(v.span, msg));
+        }
+        seen_variants.push(variant);
+    }
+    Ok(())
+}
+
+/// Validates `truncate` and `pad_start` sanitizers against the length validators, e.g.
+/// no value can be valid with `truncate = 10` and `len_char_min = 20`.
+fn validate_length_sanitizers(
+    validators: &[SpannedStringValidator],
+    sanitizers: &[StringSanitizer],
+) -> Result<(), syn::Error> {
+    // A custom sanitizer may change the length after truncation or padding.
+    if sanitizers
+        .iter()
+        .any(|s| matches!(s, StringSanitizer::With(_)))
+    {
+        return Ok(());
+    }
+    let find_len_validator = |kind: StringValidatorKind| {
+        validators.iter().find_map(|v| match v.item {
+            StringValidator::LenCharMin(ValueOrExpr::Value(len))
+            | StringValidator::LenCharMax(ValueOrExpr::Value(len))
+            | StringValidator::LenGraphemeMin(ValueOrExpr::Value(len))
+            | StringValidator::LenGraphemeMax(ValueOrExpr::Value(len))
+                if v.kind() == kind =>
+            {
+                Some((v, len))
+            }
+            _ => None,
+        })
+    };
+
+    for sanitizer in sanitizers {
+        match sanitizer {
+            StringSanitizer::Truncate {
+                len: ValueOrExpr::Value(truncate_len),
+                graphemes,
+            } => {
+                let (min_kind, max_kind) = if *graphemes {
+                    (
+                        StringValidatorKind::LenGraphemeMin,
+                        StringValidatorKind::LenGraphemeMax,
+                    )
+                } else {
+                    (
+                        StringValidatorKind::LenCharMin,
+                        StringValidatorKind::LenCharMax,
+                    )
+                };
+                if let Some((v, min_len)) = find_len_validator(min_kind)
+                    && min_len > *truncate_len
+                {
+                    let msg = format!(
+                        "`{}` is greater than `truncate` length {truncate_len}, so no value can be valid.",
+                        describe_validator(&v.item),
+                    );
+                    return Err(syn::Error::new(v.span, msg));
+                }
+                if let Some((v, max_len)) = find_len_validator(max_kind)
+                    && max_len < *truncate_len
+                {
+                    let msg = format!(
+                        "`truncate` length {truncate_len} is greater than `{}`, so longer values are rejected instead of being truncated.\nConsider truncating to {max_len}.",
+                        describe_validator(&v.item),
+                    );
+                    return Err(syn::Error::new(v.span, msg));
+                }
+            }
+            StringSanitizer::PadStart {
+                len: ValueOrExpr::Value(pad_len),
+                ..
+            } => {
+                if let Some((v, max_len)) = find_len_validator(StringValidatorKind::LenCharMax)
+                    && max_len < *pad_len
+                {
+                    let msg = format!(
+                        "`pad_start` length {pad_len} is greater than `{}`, so no value can be valid.",
+                        describe_validator(&v.item),
+                    );
+                    return Err(syn::Error::new(v.span, msg));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Validates `starts_with`, `ends_with`, `contains`, `not_contains` and `one_of` validators,
+/// their literals are known at compile time, so contradictions with the other validators
+/// and sanitizers can be detected early.
+fn validate_text_validators(
+    validators: &[SpannedStringValidator],
+    sanitizers: &[StringSanitizer],
+) -> Result<(), syn::Error> {
+    // The result of a custom sanitizer is unknown, so the sanitizers are taken into account
+    // only if all of them are built-in.
+    let sanitizers = if sanitizers
+        .iter()
+        .any(|s| matches!(s, StringSanitizer::With(_)))
+    {
+        &[]
+    } else {
+        sanitizers
+    };
+    let sanitizer_kinds: Vec<StringSanitizerKind> = sanitizers.iter().map(|s| s.kind()).collect();
+    let sanitizer_kinds = sanitizer_kinds.as_slice();
+
+    for v in validators {
+        match &v.item {
+            StringValidator::StartsWith(lit)
+            | StringValidator::EndsWith(lit)
+            | StringValidator::Contains(lit)
+            | StringValidator::NotContains(lit)
+                if lit.value().is_empty() =>
+            {
+                let msg = format!(
+                    "`{}` with an empty string makes no sense.\nEven the best of us have days like this.",
+                    v.kind()
+                );
+                return Err(syn::Error::new(v.span, msg));
+            }
+            StringValidator::OneOf(values) => {
+                validate_one_of_values(values, v.span)?;
+            }
+            _ => {}
+        }
+    }
+
+    // Prefix, suffix and substring are parts of every valid value, so they must not
+    // violate the validators, that apply to every part of a value.
+    for part in validators {
+        let (StringValidator::StartsWith(lit)
+        | StringValidator::EndsWith(lit)
+        | StringValidator::Contains(lit)) = &part.item
+        else {
+            continue;
+        };
+        let value = lit.value();
+        for v in validators {
+            let applies_to_parts = matches!(
+                v.item,
+                StringValidator::LenCharMax(_)
+                    | StringValidator::LenUtf16Max(_)
+                    | StringValidator::LenBytesMax(_)
+                    | StringValidator::Ascii
+                    | StringValidator::Alphanumeric
+                    | StringValidator::Printable
+                    | StringValidator::NoControlChars
+                    | StringValidator::NotContains(_)
+            );
+            if applies_to_parts && literal_satisfies(&v.item, &value) == Some(false) {
+                let msg = format!(
+                    "`{}` contradicts `{}`, so no value can be valid.",
+                    describe_validator(&part.item),
+                    describe_validator(&v.item),
+                );
+                return Err(syn::Error::new(part.span, msg));
+            }
+        }
+        // Trimming affects only the edges of a value, so only a prefix or a suffix
+        // with whitespaces can be broken by it.
+        // `slugify` trims the separators the same way.
+        let is_slug_edge = |c: char| Some(c) == slugify_separator(sanitizers);
+        let is_trimmed_away = match part.item {
+            StringValidator::StartsWith(_) => {
+                (trims_start(sanitizer_kinds) && value.starts_with(char::is_whitespace))
+                    || value.starts_with(is_slug_edge)
+            }
+            StringValidator::EndsWith(_) => {
+                (trims_end(sanitizer_kinds) && value.ends_with(char::is_whitespace))
+                    || value.ends_with(is_slug_edge)
+            }
+            _ => false,
+        };
+        if is_trimmed_away || apply_char_sanitizers(&value, sanitizers) != value {
+            let msg = format!(
+                "`{}` can never be satisfied, because of the sanitizers.",
+                describe_validator(&part.item),
+            );
+            return Err(syn::Error::new(part.span, msg));
+        }
+    }
+
+    // Every allowed value must pass the other validators and remain the same after sanitization.
+    for one_of in validators {
+        let StringValidator::OneOf(values) = &one_of.item else {
+            continue;
+        };
+        for lit in values {
+            let value = lit.value();
+            if let Some(v) = validators
+                .iter()
+                .find(|v| literal_satisfies(&v.item, &value) == Some(false))
+            {
+                let msg = format!(
+                    "`one_of` value {value:?} violates `{}`, so it can never be accepted.",
+                    describe_validator(&v.item),
+                );
+                return Err(syn::Error::new(lit.span(), msg));
+            }
+            let mut trimmed_value = value.as_str();
+            if trims_start(sanitizer_kinds) {
+                trimmed_value = trimmed_value.trim_start();
+            }
+            if trims_end(sanitizer_kinds) {
+                trimmed_value = trimmed_value.trim_end();
+            }
+            let mut sanitized_value = apply_char_sanitizers(trimmed_value, sanitizers);
+            if let Some(separator) = slugify_separator(sanitizers) {
+                sanitized_value = sanitized_value.trim_matches(separator).to_string();
+            }
+            for sanitizer in sanitizers {
+                if let StringSanitizer::PadStart {
+                    len: ValueOrExpr::Value(len),
+                    fill,
+                } = sanitizer
+                {
+                    let chars_count = sanitized_value.chars().count();
+                    if chars_count < *len {
+                        let padding: String =
+                            core::iter::repeat_n(fill.value(), len - chars_count).collect();
+                        sanitized_value.insert_str(0, &padding);
+                    }
+                }
+            }
+            if sanitized_value != value {
+                let msg = format!(
+                    "`one_of` value {value:?} can never be accepted, because it's changed by the sanitizers."
+                );
+                return Err(syn::Error::new(lit.span(), msg));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_one_of_values(values: &[syn::LitStr], span: Span) -> Result<(), syn::Error> {
+    if values.is_empty() {
+        let msg = "`one_of` requires at least one value.\nOtherwise nothing would ever be valid, and that's too pessimistic.";
+        return Err(syn::Error::new(span, msg));
+    }
+
+    let mut seen_values: Vec<String> = Vec::with_capacity(values.len());
+    let mut seen_names: Vec<(String, String)> = Vec::with_capacity(values.len());
+    for lit in values {
+        let value = lit.value();
+        if seen_values.contains(&value) {
+            let msg = format!("Duplicated `one_of` value {value:?}.");
+            return Err(syn::Error::new(lit.span(), msg));
+        }
+        seen_values.push(value.clone());
+        // Values without ASCII letters or digits get no constant.
+        let Some(name) = one_of_const_name(&value) else {
+            continue;
+        };
+        if name == "CONSTRAINTS" {
+            let msg = format!(
+                "`one_of` value {value:?} clashes with the generated `CONSTRAINTS` constant."
+            );
+            return Err(syn::Error::new(lit.span(), msg));
+        }
+        if let Some((other_value, _)) = seen_names.iter().find(|(_, seen_name)| seen_name == &name)
+        {
+            let msg = format!(
+                "`one_of` values {other_value:?} and {value:?} map to the same constant name `{name}`."
+            );
+            return Err(syn::Error::new(lit.span(), msg));
+        }
+        seen_names.push((value, name));
+    }
+    Ok(())
+}
+
+/// Evaluates the validator against a string known at compile time.
+/// Returns `None` if the result cannot be known (e.g. custom predicates).
+fn literal_satisfies(validator: &StringValidator, value: &str) -> Option<bool> {
+    let len_bound = |bound: &ValueOrExpr<usize>| match bound {
+        ValueOrExpr::Value(len) => Some(*len),
+        ValueOrExpr::Expr(_) => None,
+    };
+    match validator {
+        StringValidator::LenCharMin(min) => Some(value.chars().count() >= len_bound(min)?),
+        StringValidator::LenCharMax(max) => Some(value.chars().count() <= len_bound(max)?),
+        StringValidator::LenUtf16Min(min) => Some(value.encode_utf16().count() >= len_bound(min)?),
+        StringValidator::LenUtf16Max(max) => Some(value.encode_utf16().count() <= len_bound(max)?),
+        StringValidator::LenBytesMin(min) => Some(value.len() >= len_bound(min)?),
+        StringValidator::LenBytesMax(max) => Some(value.len() <= len_bound(max)?),
+        // Grapheme clusters are segmented by the runtime crate.
+        StringValidator::LenGraphemeMin(_) | StringValidator::LenGraphemeMax(_) => None,
+        StringValidator::NotEmpty => Some(!value.is_empty()),
+        StringValidator::Ascii => Some(value.is_ascii()),
+        StringValidator::Alphanumeric => Some(value.chars().all(char::is_alphanumeric)),
+        StringValidator::Printable => Some(
+            value
+                .chars()
+                .all(|c| c == ' ' || !(c.is_control() || c.is_whitespace())),
+        ),
+        StringValidator::NoControlChars => Some(!value.chars().any(char::is_control)),
+        StringValidator::StartsWith(lit) => Some(value.starts_with(&lit.value())),
+        StringValidator::EndsWith(lit) => Some(value.ends_with(&lit.value())),
+        StringValidator::Contains(lit) => Some(value.contains(&lit.value())),
+        StringValidator::NotContains(lit) => Some(!value.contains(&lit.value())),
+        StringValidator::OneOf(values) => Some(values.iter().any(|lit| lit.value() == value)),
+        StringValidator::Slug { separator } => Some(is_slug(value, *separator)),
+        StringValidator::Identifier => Some(is_identifier(value)),
+        // The formats are defined in the runtime crate, so they cannot be checked here.
+        StringValidator::Email
+        | StringValidator::Url
+        | StringValidator::Uuid
+        | StringValidator::Ipv4
+        | StringValidator::Ipv6
+        | StringValidator::Hex
+        | StringValidator::Base64
+        | StringValidator::Semver
+        | StringValidator::Iso8601Date
+        | StringValidator::Luhn
+        | StringValidator::Iban
+        | StringValidator::Isbn
+        | StringValidator::Ean
+        | StringValidator::Iso3166Alpha2
+        | StringValidator::Iso3166Alpha3
+        | StringValidator::Iso4217
+        | StringValidator::Bcp47
+        | StringValidator::Predicate(_)
+        | StringValidator::Regex(_)
+        | StringValidator::NotRegex(_) => None,
+    }
+}
+
+/// Describes the validator in error messages, e.g. `len_char_max = 5`.
+fn describe_validator(validator: &StringValidator) -> String {
+    let kind = validator.kind();
+    match validator {
+        StringValidator::LenCharMin(ValueOrExpr::Value(len))
+        | StringValidator::LenCharMax(ValueOrExpr::Value(len))
+        | StringValidator::LenUtf16Min(ValueOrExpr::Value(len))
+        | StringValidator::LenUtf16Max(ValueOrExpr::Value(len))
+        | StringValidator::LenBytesMin(ValueOrExpr::Value(len))
+        | StringValidator::LenBytesMax(ValueOrExpr::Value(len))
+        | StringValidator::LenGraphemeMin(ValueOrExpr::Value(len))
+        | StringValidator::LenGraphemeMax(ValueOrExpr::Value(len)) => format!("{kind} = {len}"),
+        StringValidator::StartsWith(lit)
+        | StringValidator::EndsWith(lit)
+        | StringValidator::Contains(lit)
+        | StringValidator::NotContains(lit) => format!("{kind} = {:?}", lit.value()),
+        StringValidator::Slug { separator } if *separator != '-' => {
+            format!("{kind}(separator = {separator:?})")
+        }
+        _ => kind.to_string(),
+    }
+}
+
+/// Applies the sanitizers, that change characters regardless of their position in a value.
+/// Truncation is applied too: a part of a value, that is longer than the limit, is cut off
+/// the same way as the whole value.
+fn apply_char_sanitizers(value: &str, sanitizers: &[StringSanitizer]) -> String {
+    sanitizers
+        .iter()
+        .fold(value.to_string(), |value, sanitizer| match sanitizer {
+            StringSanitizer::Lowercase => value.to_lowercase(),
+            StringSanitizer::Uppercase => value.to_uppercase(),
+            StringSanitizer::RemoveWhitespace => value.replace(char::is_whitespace, ""),
+            StringSanitizer::CollapseWhitespace => collapse_whitespace(&value),
+            StringSanitizer::StripControlChars => value.replace(is_control_or_zero_width, ""),
+            StringSanitizer::Replace { from, to } => value.replace(&from.value(), &to.value()),
+            StringSanitizer::Truncate {
+                len: ValueOrExpr::Value(len),
+                graphemes: false,
+            } => value.chars().take(*len).collect(),
+            #[cfg(feature = "unicode")]
+            StringSanitizer::Nfc => value.nfc().collect(),
+            #[cfg(feature = "unicode")]
+            StringSanitizer::Nfd => value.nfd().collect(),
+            #[cfg(feature = "unicode")]
+            StringSanitizer::Nfkc => value.nfkc().collect(),
+            #[cfg(feature = "unicode")]
+            StringSanitizer::Nfkd => value.nfkd().collect(),
+            #[cfg(feature = "unicode")]
+            StringSanitizer::Casefold => caseless::default_case_fold_str(&value),
+            #[cfg(feature = "unicode")]
+            StringSanitizer::Slugify { separator } => slugify_keeping_edges(&value, *separator),
+            _ => value,
+        })
+}
+
+fn trims_start(sanitizer_kinds: &[StringSanitizerKind]) -> bool {
+    sanitizer_kinds.contains(&StringSanitizerKind::Trim)
+        || sanitizer_kinds.contains(&StringSanitizerKind::TrimStart)
+}
+
+fn trims_end(sanitizer_kinds: &[StringSanitizerKind]) -> bool {
+    sanitizer_kinds.contains(&StringSanitizerKind::Trim)
+        || sanitizer_kinds.contains(&StringSanitizerKind::TrimEnd)
+}
+
+fn slugify_separator(sanitizers: &[StringSanitizer]) -> Option<char> {
+    sanitizers.iter().find_map(|s| match s {
+        StringSanitizer::Slugify { separator } => Some(*separator),
+        _ => None,
+    })
+}
+
 fn validate_sanitizers(
     sanitizers: Vec<SpannedStringSanitizer>,
 ) -> Result<Vec<StringSanitizer>, syn::Error> {
-    validate_duplicates(&sanitizers, |kind| {
+    // `replace` can be used multiple times to replace different substrings.
+    let unique_sanitizers: Vec<SpannedStringSanitizer> = sanitizers
+        .iter()
+        .filter(|s| s.kind() != StringSanitizerKind::Replace)
+        .cloned()
+        .collect();
+    validate_duplicates(&unique_sanitizers, |kind| {
         format!(
             "Duplicated sanitizer `{kind}`.\nYou're doing well, it's not that bad unless you forgot to call your mom!"
         )
     })?;
 
-    // Validate lowercase VS uppercase
-    let lowercase = sanitizers
-        .iter()
-        .find(|&s| s.kind() == StringSanitizerKind::Lowercase);
-    let uppercase = sanitizers
-        .iter()
-        .find(|&s| s.kind() == StringSanitizerKind::Uppercase);
-    if let (Some(lowercase), Some(uppercase)) = (lowercase, uppercase) {
-        let msg = format!(
-            "Using both sanitizers `{}` and `{}` makes no sense.\nYou're a great developer! Take care of yourself, a 5 mins break may help.",
-            lowercase.kind(),
-            uppercase.kind()
-        );
-        let span = lowercase.span;
-        let err = syn::Error::new(span, msg);
-        return Err(err);
+    for sanitizer in &sanitizers {
+        match &sanitizer.item {
+            StringSanitizer::Replace { from, to }
+                if from.value().is_empty() || from.value() == to.value() =>
+            {
+                let msg = format!(
+                    "`replace` from {:?} to {:?} makes no sense.\nEven the best of us have days like this.",
+                    from.value(),
+                    to.value()
+                );
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+            StringSanitizer::Truncate {
+                len: ValueOrExpr::Value(0),
+                ..
+            }
+            | StringSanitizer::PadStart {
+                len: ValueOrExpr::Value(0),
+                ..
+            } => {
+                let msg = format!(
+                    "`{}` with zero length makes no sense.\nEven the best of us have days like this.",
+                    sanitizer.kind()
+                );
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+            _ => {}
+        }
+    }
+
+    // Validate lowercase VS uppercase, nfc VS nfd, etc.
+    // Every sanitizer of a group overrides the result of the others.
+    const CONFLICTING_SANITIZERS: [&[StringSanitizerKind]; 2] = [
+        &[
+            StringSanitizerKind::Lowercase,
+            StringSanitizerKind::Uppercase,
+            StringSanitizerKind::Casefold,
+        ],
+        &[
+            StringSanitizerKind::Nfc,
+            StringSanitizerKind::Nfd,
+            StringSanitizerKind::Nfkc,
+            StringSanitizerKind::Nfkd,
+        ],
+    ];
+    for group in CONFLICTING_SANITIZERS {
+        let mut conflicting = group
+            .iter()
+            .filter_map(|&kind| sanitizers.iter().find(|s| s.kind() == kind));
+        if let (Some(first), Some(second)) = (conflicting.next(), conflicting.next()) {
+            let msg = format!(
+                "Using both sanitizers `{}` and `{}` makes no sense.\nYou're a great developer! Take care of yourself, a 5 mins break may help.",
+                first.kind(),
+                second.kind()
+            );
+            let err = syn::Error::new(first.span, msg);
+            return Err(err);
+        }
     }
 
     let sanitizers: Vec<StringSanitizer> = sanitizers.into_iter().map(|s| s.item).collect();
@@ -197,22 +798,85 @@ pub(crate) fn to_string_derive_trait(
     }
 }
 
-#[cfg(feature = "regex")]
+#[cfg(any(feature = "regex", feature = "regex_lite"))]
 mod regex_validation {
     use super::*;
-    use crate::string::models::RegexDef;
+    use crate::string::{
+        models::{RegexDef, RegexRule},
+        regex_captures::{RESERVED_METHOD_NAMES, named_capture_groups},
+    };
+    use cfg_if::cfg_if;
 
-    pub fn validate_regex_def(regex_def: &RegexDef, span: Span) -> Result<(), syn::Error> {
-        match regex_def {
+    pub fn validate_regex_rule(rule: &RegexRule, span: Span) -> Result<(), syn::Error> {
+        match &rule.def {
             RegexDef::StringLiteral(lit) => {
-                // Try to validate regex at compile time if it's a string literal
+                // Try to validate regex at compile time if it's a string literal.
+                // The engine must be the same as the one picked by `nutype` at runtime:
+                // `regex::bytes` for `bytes` rules, otherwise `regex` takes precedence over `regex_lite`.
                 let regex_str = lit.value();
-                match regex::Regex::new(&regex_str) {
-                    Ok(_re) => Ok(()),
-                    Err(err) => Err(syn::Error::new(span, format!("{err}"))),
+                cfg_if! {
+                    if #[cfg(feature = "regex")] {
+                        let result = if rule.bytes {
+                            regex::bytes::Regex::new(&regex_str).map(drop)
+                        } else {
+                            regex::Regex::new(&regex_str).map(drop)
+                        };
+                    } else {
+                        let result = regex_lite::Regex::new(&regex_str).map(drop);
+                    }
                 }
+                result.map_err(|err| syn::Error::new(span, err.to_string()))?;
+                // The DFA is built once more by the generator, that embeds it.
+                #[cfg(feature = "regex_dfa")]
+                if rule.dfa {
+                    crate::string::regex_dfa::build_serialized_dfa(&regex_str, rule.bytes)
+                        .map_err(|err| syn::Error::new(span, err))?;
+                }
+                Ok(())
             }
             RegexDef::Path(_) => Ok(()),
         }
     }
+
+    /// Named capture groups of `regex` rules with `captures` flag are turned into accessor methods,
+    /// so their names must be unique identifiers, that do not clash with the generated methods.
+    pub fn validate_capture_groups(
+        validators: &[SpannedStringValidator],
+    ) -> Result<(), syn::Error> {
+        let mut seen_names: Vec<String> = Vec::new();
+        for v in validators {
+            let StringValidator::Regex(RegexRule {
+                def: RegexDef::StringLiteral(lit),
+                captures: true,
+                ..
+            }) = &v.item
+            else {
+                continue;
+            };
+            for group in named_capture_groups(&lit.value()) {
+                let is_identifier = group.accessor_ident(v.span).is_some();
+                let name = group.name;
+                if !is_identifier {
+                    let msg = format!(
+                        "Capture group `{name}` cannot be turned into an accessor method, since it's not a valid identifier.\nConsider renaming the group, e.g. `(?P<area_code>[0-9]{{3}})`."
+                    );
+                    return Err(syn::Error::new(v.span, msg));
+                }
+                if RESERVED_METHOD_NAMES.contains(&name.as_str()) {
+                    let msg = format!(
+                        "Capture group `{name}` clashes with `{name}()` method generated by nutype.\nConsider renaming the group."
+                    );
+                    return Err(syn::Error::new(v.span, msg));
+                }
+                if seen_names.contains(&name) {
+                    let msg = format!(
+                        "Capture group `{name}` is defined by multiple `regex` rules.\nEvery group must have its own name, since it's turned into an accessor method."
+                    );
+                    return Err(syn::Error::new(v.span, msg));
+                }
+                seen_names.push(name);
+            }
+        }
+        Ok(())
+    }
 }
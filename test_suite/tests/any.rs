@@ -1198,3 +1198,78 @@ mod cfg_attr {
         assert_eq!(val.into_inner(), vec![0]);
     }
 }
+
+#[cfg(test)]
+mod report_all {
+    use super::*;
+
+    #[nutype(
+        validate(predicate = |v: &Vec<i32>| !v.is_empty(), report = all),
+        derive(Debug, PartialEq)
+    )]
+    pub struct NonEmptyVec(Vec<i32>);
+
+    #[test]
+    fn test_collects_violations() {
+        let errors = NonEmptyVec::try_new(vec![]).unwrap_err();
+        assert_eq!(errors.into_vec(), vec![NonEmptyVecError::PredicateViolated]);
+
+        assert_eq!(NonEmptyVec::try_new(vec![1]).unwrap().into_inner(), vec![1]);
+    }
+}
+
+#[cfg(test)]
+mod constraints {
+    use super::*;
+    use nutype::Constraint;
+
+    #[nutype(
+        sanitize(with = |mut v: Vec<i32>| { v.sort(); v }),
+        validate(predicate = |v: &Vec<i32>| !v.is_empty())
+    )]
+    pub struct SortedVec(Vec<i32>);
+
+    #[test]
+    fn test_constraints() {
+        assert_eq!(
+            SortedVec::CONSTRAINTS,
+            &[
+                Constraint::Sanitizer { kind: "with" },
+                Constraint::Validator {
+                    kind: "predicate",
+                    value: None
+                },
+            ]
+        );
+        assert_eq!(
+            SortedVec::try_new(vec![2, 1]).unwrap().into_inner(),
+            vec![1, 2]
+        );
+    }
+}
+
+#[cfg(test)]
+mod validate_and_sanitize {
+    use super::*;
+
+    #[nutype(
+        sanitize(with = |mut v: Vec<i32>| { v.dedup(); v }),
+        validate(predicate = |v: &Vec<i32>| v.len() <= 2),
+        derive(Debug)
+    )]
+    pub struct ShortVec(Vec<i32>);
+
+    #[test]
+    fn test_validate_and_sanitize() {
+        assert!(ShortVec::is_valid(&vec![1, 2]));
+        assert_eq!(
+            ShortVec::validate(&vec![1, 1, 2]),
+            Err(ShortVecError::PredicateViolated)
+        );
+        assert_eq!(ShortVec::sanitize(vec![1, 1, 2]), vec![1, 2]);
+        assert_eq!(
+            ShortVec::try_new(vec![1, 1, 2]).unwrap().into_inner(),
+            vec![1, 2]
+        );
+    }
+}
@@ -934,3 +934,152 @@ mod cfg_attr {
         assert_eq!(val, Score::new(0.0));
     }
 }
+
+#[cfg(test)]
+mod report_all {
+    use super::*;
+
+    #[nutype(
+        validate(finite, greater_or_equal = 0.0, less = 1.0, report = all),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Ratio(f64);
+
+    #[test]
+    fn test_collects_all_violations() {
+        let errors = Ratio::try_new(f64::NEG_INFINITY).unwrap_err();
+        assert_eq!(
+            errors.into_vec(),
+            vec![
+                RatioError::FiniteViolated,
+                RatioError::GreaterOrEqualViolated
+            ]
+        );
+
+        let errors = Ratio::try_new(f64::NAN).unwrap_err();
+        assert!(errors.contains(&RatioError::FiniteViolated));
+
+        assert_eq!(Ratio::try_new(0.5).unwrap().into_inner(), 0.5);
+    }
+}
+
+#[cfg(test)]
+mod detailed_errors {
+    use super::*;
+
+    #[nutype(
+        validate(finite, greater_or_equal = 0.0, less_or_equal = 1.0, detailed_errors),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Probability(f64);
+
+    #[test]
+    fn test_variants_carry_bound_and_actual_value() {
+        assert_eq!(
+            Probability::try_new(-0.5),
+            Err(ProbabilityError::GreaterOrEqualViolated {
+                min: 0.0,
+                actual: -0.5
+            })
+        );
+        assert_eq!(
+            Probability::try_new(1.5),
+            Err(ProbabilityError::LessOrEqualViolated {
+                max: 1.0,
+                actual: 1.5
+            })
+        );
+        assert_eq!(
+            Probability::try_new(f64::INFINITY),
+            Err(ProbabilityError::FiniteViolated)
+        );
+    }
+
+    #[test]
+    fn test_with_report_all() {
+        #[nutype(
+            validate(greater = 0.0, less = 10.0, predicate = |v| *v != 5.0, detailed_errors, report = all),
+            derive(Debug)
+        )]
+        pub struct Weight(f32);
+
+        let errors = Weight::try_new(-1.0).unwrap_err();
+        assert_eq!(
+            errors.as_slice(),
+            &[WeightError::GreaterViolated {
+                min: 0.0,
+                actual: -1.0
+            }]
+        );
+    }
+}
+
+mod validation_error {
+    use super::*;
+    use nutype::ValidationError;
+
+    #[nutype(validate(finite, greater = 0.0), derive(Debug))]
+    pub struct Weight(f64);
+
+    #[test]
+    fn test_code_type_name_and_rule() {
+        let err = Weight::try_new(f64::NAN).unwrap_err();
+        assert_eq!(err.code(), "finite");
+        assert_eq!(err.rule(), "finite");
+
+        let err = Weight::try_new(-1.0).unwrap_err();
+        assert_eq!(err.code(), "greater");
+        assert_eq!(err.type_name(), "Weight");
+        assert_eq!(err.rule(), "greater = 0.0");
+    }
+}
+
+mod nutype_trait {
+    use super::*;
+    use nutype::Nutype;
+
+    #[nutype(validate(finite), derive(Debug))]
+    pub struct Ratio(f64);
+
+    fn double<T: Nutype<Inner = f64>>(value: T) -> Result<T, T::Error> {
+        T::try_new(value.into_inner() * 2.0)
+    }
+
+    #[test]
+    fn test_generic_function() {
+        let ratio = Ratio::try_new(0.25).unwrap();
+        assert_eq!(double(ratio).unwrap().into_inner(), 0.5);
+
+        let ratio = Ratio::try_new(f64::MAX).unwrap();
+        assert_eq!(double(ratio).unwrap_err(), RatioError::FiniteViolated);
+    }
+}
+
+mod constraints {
+    use super::*;
+    use nutype::{Constraint, ConstraintValue};
+
+    #[nutype(validate(finite, greater_or_equal = 0.5, less = 10.0))]
+    pub struct Factor(f32);
+
+    #[test]
+    fn test_constraints() {
+        assert_eq!(
+            Factor::CONSTRAINTS,
+            &[
+                Constraint::Validator {
+                    kind: "finite",
+                    value: None
+                },
+                Constraint::Validator {
+                    kind: "greater_or_equal",
+                    value: Some(ConstraintValue::Float(0.5))
+                },
+                Constraint::Validator {
+                    kind: "less",
+                    value: Some(ConstraintValue::Float(10.0))
+                },
+            ]
+        );
+    }
+}
@@ -1096,3 +1096,402 @@ mod cfg_attr {
         assert_eq!(a, b);
     }
 }
+
+#[cfg(test)]
+mod report_all {
+    use super::*;
+
+    #[nutype(
+        validate(greater = 10, less_or_equal = 100, predicate = |n| n % 2 == 0, report = all),
+        derive(Debug, PartialEq, TryFrom, FromStr)
+    )]
+    pub struct EvenNumber(i32);
+
+    #[test]
+    fn test_collects_all_violations() {
+        let errors = EvenNumber::try_new(7).unwrap_err();
+        assert_eq!(
+            errors.as_slice(),
+            &[
+                EvenNumberError::GreaterViolated,
+                EvenNumberError::PredicateViolated
+            ]
+        );
+
+        let errors = EvenNumber::try_new(102).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors.contains(&EvenNumberError::LessOrEqualViolated));
+
+        assert_eq!(EvenNumber::try_new(42).unwrap().into_inner(), 42);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "7".parse::<EvenNumber>().unwrap_err();
+        match err {
+            EvenNumberParseError::Validate(errors) => {
+                assert_eq!(errors.len(), 2);
+            }
+            EvenNumberParseError::Parse(_) => panic!("Expected validation error"),
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let errors = EvenNumber::try_from(7).unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "EvenNumber is too small. The value must be greater than 10. EvenNumber failed the predicate test."
+        );
+    }
+}
+
+#[cfg(test)]
+mod detailed_errors {
+    use super::*;
+
+    const MIN_AGE: u8 = 18;
+
+    #[nutype(
+        validate(greater_or_equal = MIN_AGE, less = 150, detailed_errors),
+        derive(Debug, PartialEq, FromStr)
+    )]
+    pub struct Age(u8);
+
+    #[test]
+    fn test_variants_carry_bound_and_actual_value() {
+        assert_eq!(
+            Age::try_new(17),
+            Err(AgeError::GreaterOrEqualViolated {
+                min: 18,
+                actual: 17
+            })
+        );
+        assert_eq!(
+            Age::try_new(200),
+            Err(AgeError::LessViolated {
+                max: 150,
+                actual: 200
+            })
+        );
+        assert_eq!(Age::try_new(30).unwrap().into_inner(), 30);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "5".parse::<Age>().unwrap_err();
+        assert!(matches!(
+            err,
+            AgeParseError::Validate(AgeError::GreaterOrEqualViolated { min: 18, actual: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_display_is_not_affected() {
+        let err = Age::try_new(17).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Age is too small. The value must be greater or equal to 18."
+        );
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        #[nutype(
+            validate(greater = -10, less_or_equal = 10, detailed_errors),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Offset(i64);
+
+        assert_eq!(
+            Offset::try_new(-10),
+            Err(OffsetError::GreaterViolated {
+                min: -10,
+                actual: -10
+            })
+        );
+        assert_eq!(
+            Offset::try_new(11),
+            Err(OffsetError::LessOrEqualViolated {
+                max: 10,
+                actual: 11
+            })
+        );
+    }
+}
+
+mod validation_error {
+    use super::*;
+    use nutype::ValidationError;
+
+    const MAX_LEVEL: u8 = 80;
+
+    #[derive(Debug, PartialEq)]
+    pub struct OddError;
+
+    impl core::fmt::Display for OddError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "odd")
+        }
+    }
+
+    impl core::error::Error for OddError {}
+
+    fn validate_even(n: &i32) -> Result<(), OddError> {
+        if n % 2 == 0 {
+            Ok(())
+        } else {
+            Err(OddError)
+        }
+    }
+
+    #[nutype(
+        validate(greater_or_equal = 1, less_or_equal = MAX_LEVEL),
+        derive(Debug, FromStr)
+    )]
+    pub struct Level(u8);
+
+    #[nutype(
+        validate(with = validate_even, error = OddError),
+        derive(Debug, FromStr)
+    )]
+    pub struct Even(i32);
+
+    #[test]
+    fn test_code_type_name_and_rule() {
+        let err = Level::try_new(0).unwrap_err();
+        assert_eq!(err.code(), "greater_or_equal");
+        assert_eq!(err.type_name(), "Level");
+        assert_eq!(err.rule(), "greater_or_equal = 1");
+    }
+
+    #[test]
+    fn test_rule_with_constant_bound() {
+        let err = Level::try_new(81).unwrap_err();
+        assert_eq!(err.code(), "less_or_equal");
+        assert_eq!(err.rule(), "less_or_equal = MAX_LEVEL");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "abc".parse::<Level>().unwrap_err();
+        assert_eq!(err.code(), "parse");
+        assert_eq!(err.type_name(), "Level");
+        assert_eq!(err.rule(), "parse");
+
+        let err = "99".parse::<Level>().unwrap_err();
+        assert_eq!(err.code(), "less_or_equal");
+        assert_eq!(err.type_name(), "Level");
+        assert_eq!(err.rule(), "less_or_equal = MAX_LEVEL");
+    }
+
+    #[test]
+    fn test_parse_error_with_custom_validation() {
+        let err = "3".parse::<Even>().unwrap_err();
+        assert_eq!(err.code(), "with");
+        assert_eq!(err.rule(), "with = validate_even");
+    }
+
+    #[test]
+    fn test_errors_collection() {
+        #[nutype(
+            validate(greater = 10, predicate = |n| n % 2 == 0, report = all),
+            derive(Debug)
+        )]
+        pub struct Score(i32);
+
+        let errors = Score::try_new(3).unwrap_err();
+        assert_eq!(errors.code(), "greater");
+        assert_eq!(errors.type_name(), "Score");
+        assert_eq!(errors.rule(), "greater = 10");
+    }
+}
+
+mod nutype_trait {
+    use super::*;
+    use nutype::Nutype;
+
+    fn load<T: Nutype>(raw: T::Inner) -> Result<T, T::Error> {
+        T::try_new(raw)
+    }
+
+    #[nutype(validate(greater = 0), derive(Debug, PartialEq))]
+    pub struct UserId(i64);
+
+    #[nutype(
+        validate(greater = 0, less = 10, report = all),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Digit(u8);
+
+    #[test]
+    fn test_validated_type() {
+        let id: UserId = load(42).unwrap();
+        assert_eq!(id.as_inner(), &42);
+        assert_eq!(load::<UserId>(0), Err(UserIdError::GreaterViolated));
+    }
+
+    #[test]
+    fn test_error_is_collection_with_report_all() {
+        let errors: DigitErrors = load::<Digit>(0).unwrap_err();
+        assert_eq!(errors.as_slice(), &[DigitError::GreaterViolated]);
+    }
+}
+
+mod constraints {
+    use super::*;
+    use nutype::{Constraint, ConstraintValue};
+
+    const MIN_TEMP: i16 = -50;
+    static MAX_OFFSET: i16 = 16380;
+
+    #[nutype(
+        sanitize(with = |n| n.clamp(-100, 100)),
+        validate(greater_or_equal = MIN_TEMP, less = MIN_TEMP + 110, predicate = |n| n % 2 == 0)
+    )]
+    pub struct Temperature(i16);
+
+    #[nutype(validate(greater = i16::MIN / 2, less_or_equal = MAX_OFFSET))]
+    pub struct Offset(i16);
+
+    #[nutype(validate(less_or_equal = 200))]
+    pub struct Percent(u8);
+
+    #[test]
+    fn test_signed_bounds() {
+        assert_eq!(
+            Temperature::CONSTRAINTS,
+            &[
+                Constraint::Sanitizer { kind: "with" },
+                Constraint::Validator {
+                    kind: "greater_or_equal",
+                    value: Some(ConstraintValue::Signed(-50))
+                },
+                Constraint::Validator {
+                    kind: "less",
+                    value: Some(ConstraintValue::Signed(60))
+                },
+                Constraint::Validator {
+                    kind: "predicate",
+                    value: None
+                },
+            ]
+        );
+        assert!(Temperature::try_new(58).is_ok());
+        assert!(Temperature::try_new(60).is_err());
+    }
+
+    #[test]
+    fn test_bounds_with_primitive_consts_and_statics() {
+        assert_eq!(
+            Offset::CONSTRAINTS,
+            &[
+                Constraint::Validator {
+                    kind: "greater",
+                    value: Some(ConstraintValue::Signed(-16384))
+                },
+                Constraint::Validator {
+                    kind: "less_or_equal",
+                    value: Some(ConstraintValue::Signed(16380))
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsigned_bounds() {
+        assert_eq!(
+            Percent::CONSTRAINTS,
+            &[Constraint::Validator {
+                kind: "less_or_equal",
+                value: Some(ConstraintValue::Unsigned(200))
+            }]
+        );
+    }
+}
+
+mod validate_and_sanitize {
+    use super::*;
+
+    const fn clamp_speed(n: i32) -> i32 {
+        if n > 100 {
+            100
+        } else {
+            n
+        }
+    }
+
+    #[nutype(
+        const_fn,
+        sanitize(with = clamp_speed),
+        validate(greater_or_equal = 0),
+        derive(Debug)
+    )]
+    pub struct Speed(i32);
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Speed::validate(&5), Ok(()));
+        assert_eq!(
+            Speed::validate(&-1),
+            Err(SpeedError::GreaterOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_functions_are_const() {
+        const VALIDATION: [bool; 2] = [Speed::is_valid(&10), Speed::is_valid(&-10)];
+        const SANITIZED: i32 = Speed::sanitize(500);
+
+        assert_eq!(VALIDATION, [true, false]);
+        assert_eq!(SANITIZED, 100);
+    }
+}
+
+mod luhn {
+    use super::*;
+
+    #[test]
+    fn test_luhn_unsigned() {
+        #[nutype(validate(luhn), derive(Debug, PartialEq))]
+        pub struct Imei(u64);
+
+        assert!(Imei::try_new(490154203237518).is_ok());
+        assert!(Imei::try_new(0).is_ok());
+        assert!(Imei::try_new(18).is_ok());
+        assert_eq!(Imei::try_new(490154203237519), Err(ImeiError::LuhnViolated));
+        assert_eq!(Imei::try_new(1), Err(ImeiError::LuhnViolated));
+        assert_eq!(
+            ImeiError::LuhnViolated.to_string(),
+            "Imei does not have a valid Luhn check digit."
+        );
+    }
+
+    #[test]
+    fn test_luhn_signed() {
+        #[nutype(validate(luhn), derive(Debug, PartialEq))]
+        pub struct Code(i32);
+
+        assert!(Code::try_new(79927398).is_ok());
+        assert_eq!(Code::try_new(-79927398), Err(CodeError::LuhnViolated));
+        assert_eq!(Code::try_new(79927399), Err(CodeError::LuhnViolated));
+    }
+
+    #[test]
+    fn test_luhn_u128_with_other_validators() {
+        #[nutype(
+            const_fn,
+            validate(greater = 9, luhn),
+            derive(Debug, PartialEq, Clone, Copy)
+        )]
+        pub struct Number(u128);
+
+        const VALID: Number = match Number::try_new(79927398713) {
+            Ok(number) => number,
+            Err(_) => panic!("Invalid number"),
+        };
+        assert_eq!(VALID.into_inner(), 79927398713);
+        assert_eq!(Number::try_new(0), Err(NumberError::GreaterViolated));
+        assert_eq!(Number::try_new(u128::MAX), Err(NumberError::LuhnViolated));
+    }
+}
@@ -0,0 +1,21 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(with = validate_num, error = NumError, detailed_errors)
+)]
+pub struct Num(i32);
+
+fn validate_num(val: &i32) -> Result<(), NumError> {
+    if *val > 100 {
+        Err(NumError::TooBig)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+enum NumError {
+    TooBig,
+}
+
+fn main () {}
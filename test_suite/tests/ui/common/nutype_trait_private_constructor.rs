@@ -0,0 +1,19 @@
+mod inner {
+    use nutype::nutype;
+
+    // Type with private constructor must not expose it through `Nutype` trait
+    #[nutype(
+        validate(greater = 0),
+        constructor(visibility = private),
+        derive(Debug),
+    )]
+    pub struct Id(i64);
+}
+
+fn load<T: nutype::Nutype>(raw: T::Inner) -> Result<T, T::Error> {
+    T::try_new(raw)
+}
+
+fn main() {
+    let _id = load::<inner::Id>(1);
+}
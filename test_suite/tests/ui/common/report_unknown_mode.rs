@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater = 0, less = 100, report = everything)
+)]
+pub struct Num(i32);
+
+fn main () {}
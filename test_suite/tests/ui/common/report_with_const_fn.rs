@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    const_fn,
+    validate(greater = 0, less = 100, report = all)
+)]
+pub struct Num(i32);
+
+fn main () {}
@@ -0,0 +1,15 @@
+mod inner {
+    use nutype::nutype;
+
+    // `validate()` follows the visibility of the constructor
+    #[nutype(
+        validate(not_empty),
+        constructor(visibility = private),
+        derive(Debug),
+    )]
+    pub struct Name(String);
+}
+
+fn main() {
+    let _ = inner::Name::validate("test");
+}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(nfkc))]
+pub struct Username(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(pad_start(len = 10, char = '0')), validate(len_char_max = 8))]
+pub struct AccountNumber(String);
+
+fn main() {}
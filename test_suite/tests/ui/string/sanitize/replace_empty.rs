@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(replace(from = "", to = " ")))]
+pub struct Text(String);
+
+fn main() {}
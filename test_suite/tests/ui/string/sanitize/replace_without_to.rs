@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(replace(from = "\t")))]
+pub struct Text(String);
+
+fn main() {}
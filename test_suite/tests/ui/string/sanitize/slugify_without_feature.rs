@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(slugify))]
+pub struct Slug(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(truncate = 300), validate(len_char_max = 255))]
+pub struct Title(String);
+
+fn main() {}
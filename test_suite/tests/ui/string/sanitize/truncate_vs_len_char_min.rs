@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(truncate = 3), validate(len_char_min = 5))]
+pub struct Title(String);
+
+fn main() {}
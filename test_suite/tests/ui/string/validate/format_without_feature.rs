@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(email))]
+pub struct Email(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_grapheme_max = 20))]
+pub struct DisplayName(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(iso4217))]
+pub struct CurrencyCode(String);
+
+fn main () {}
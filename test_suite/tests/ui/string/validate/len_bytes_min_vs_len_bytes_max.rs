@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_bytes_min = 64, len_bytes_max = 32))]
+pub struct Column(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(one_of = ["en-us", "EN_US"]))]
+pub struct Locale(String);
+
+fn main () {}
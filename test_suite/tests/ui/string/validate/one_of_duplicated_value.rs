@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(one_of = ["red", "green", "red"]))]
+pub struct Color(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_char_max = 3, one_of = ["EUR", "USD", "Hryvnia"]))]
+pub struct Currency(String);
+
+fn main () {}
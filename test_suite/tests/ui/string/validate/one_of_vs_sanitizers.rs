@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(lowercase), validate(one_of = ["en", "en-US"]))]
+pub struct Locale(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(slug(separator = 'x')))]
+pub struct Slug(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(trim, uppercase), validate(slug))]
+pub struct Slug(String);
+
+fn main () {}
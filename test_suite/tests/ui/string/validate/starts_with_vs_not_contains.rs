@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(starts_with = "https://", not_contains = "//"))]
+pub struct Url(String);
+
+fn main () {}
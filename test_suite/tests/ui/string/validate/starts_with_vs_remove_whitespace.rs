@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(remove_whitespace), validate(starts_with = "Mr. "))]
+pub struct Name(String);
+
+fn main () {}